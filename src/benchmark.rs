@@ -0,0 +1,163 @@
+use anyhow::{Context, Result};
+use reqwest::Client;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::Path;
+use std::sync::Arc;
+use std::time::Instant;
+
+use crate::version::VersionSet;
+use crate::{cache, downloader, installer};
+
+/// A named benchmark scenario loaded from a JSON workload file.
+#[derive(Debug, Deserialize)]
+pub struct Workload {
+    pub name: String,
+    pub packages: Vec<String>,
+    pub python_version: String,
+    pub iterations: usize,
+    #[serde(default)]
+    pub warmup: usize,
+}
+
+/// Timing and size data for a single resolve+download+install cycle.
+#[derive(Debug, Clone, Serialize)]
+pub struct PerformanceMetrics {
+    pub download_time: f64,
+    pub install_time: f64,
+    pub total_time: f64,
+    pub size: u64,
+    pub speed: f64,
+}
+
+#[derive(Debug, Serialize)]
+struct Aggregate {
+    min: f64,
+    median: f64,
+    max: f64,
+    mean: f64,
+}
+
+fn aggregate(values: &[f64]) -> Aggregate {
+    let mut sorted = values.to_vec();
+    sorted.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    let mean = sorted.iter().sum::<f64>() / sorted.len() as f64;
+    let median = sorted[sorted.len() / 2];
+    Aggregate {
+        min: *sorted.first().unwrap_or(&0.0),
+        median,
+        max: *sorted.last().unwrap_or(&0.0),
+        mean,
+    }
+}
+
+#[derive(Serialize)]
+struct Report {
+    workload: String,
+    crate_version: String,
+    build_id: String,
+    iterations: Vec<PerformanceMetrics>,
+    total_time: Aggregate,
+    download_time: Aggregate,
+    install_time: Aggregate,
+    speed: Aggregate,
+}
+
+async fn run_one_install(client: &Arc<Client>, package: &str, python_version: &str) -> Result<PerformanceMetrics> {
+    let total_start = Instant::now();
+
+    let resolved = crate::dependency::resolve_dependencies(
+        package,
+        &[],
+        &VersionSet::default(),
+        python_version,
+        &["pypi.org".to_string(), "files.pythonhosted.org".to_string()],
+        false,
+        client,
+    )
+    .await?;
+    let dep = resolved
+        .iter()
+        .find(|d| d.name.eq_ignore_ascii_case(package))
+        .context("resolver did not produce a pin for the root package")?
+        .clone();
+
+    let filename = dep.source.split('/').next_back().unwrap_or(&dep.name).to_string();
+    let wheel_tag = cache::wheel_tag_from_filename(&filename);
+
+    let download_start = Instant::now();
+    downloader::download_package(client, &dep.source, &filename, dep.sha256.as_deref()).await?;
+    let download_time = download_start.elapsed().as_secs_f64();
+
+    let size = fs::metadata(&filename).map(|m| m.len()).unwrap_or(0);
+    let cached = cache::cache_package(&dep.name, &dep.version, &wheel_tag, &filename, &dep.source, dep.sha256.as_deref())?;
+
+    let install_start = Instant::now();
+    installer::install_wheel(cached.to_str().unwrap_or(""), None)?;
+    let install_time = install_start.elapsed().as_secs_f64();
+
+    let total_time = total_start.elapsed().as_secs_f64();
+    let speed = if download_time > 0.0 { size as f64 / download_time } else { 0.0 };
+
+    Ok(PerformanceMetrics {
+        download_time,
+        install_time,
+        total_time,
+        size,
+        speed,
+    })
+}
+
+/// Run every package in the workload `iterations` times (after `warmup` untimed runs),
+/// printing a human-readable summary table and optionally POSTing the raw results.
+pub async fn run_workload(client: &Arc<Client>, workload_path: &Path, report_url: Option<&str>) -> Result<()> {
+    let content = fs::read_to_string(workload_path)
+        .with_context(|| format!("failed to read workload file {:?}", workload_path))?;
+    let workload: Workload = serde_json::from_str(&content)
+        .with_context(|| format!("{:?} is not a valid workload file", workload_path))?;
+
+    println!("Running benchmark '{}' ({} packages, {} iterations, {} warmup)",
+        workload.name, workload.packages.len(), workload.iterations, workload.warmup);
+
+    for package in &workload.packages {
+        for _ in 0..workload.warmup {
+            run_one_install(client, package, &workload.python_version).await?;
+        }
+
+        let mut metrics = Vec::with_capacity(workload.iterations);
+        for i in 0..workload.iterations {
+            let m = run_one_install(client, package, &workload.python_version).await?;
+            println!("  [{}] iteration {}/{}: {:.2}s total, {:.2} MB/s",
+                package, i + 1, workload.iterations, m.total_time, m.speed / 1_000_000.0);
+            metrics.push(m);
+        }
+
+        print_summary(package, &metrics);
+
+        if let Some(url) = report_url {
+            let report = Report {
+                workload: workload.name.clone(),
+                crate_version: env!("CARGO_PKG_VERSION").to_string(),
+                build_id: std::env::var("GIT_COMMIT").unwrap_or_else(|_| "unknown".to_string()),
+                total_time: aggregate(&metrics.iter().map(|m| m.total_time).collect::<Vec<_>>()),
+                download_time: aggregate(&metrics.iter().map(|m| m.download_time).collect::<Vec<_>>()),
+                install_time: aggregate(&metrics.iter().map(|m| m.install_time).collect::<Vec<_>>()),
+                speed: aggregate(&metrics.iter().map(|m| m.speed).collect::<Vec<_>>()),
+                iterations: metrics,
+            };
+            client.post(url).json(&report).send().await?;
+            println!("  ✅ Reported results for {} to {}", package, url);
+        }
+    }
+
+    Ok(())
+}
+
+fn print_summary(package: &str, metrics: &[PerformanceMetrics]) {
+    let total = aggregate(&metrics.iter().map(|m| m.total_time).collect::<Vec<_>>());
+    let speed = aggregate(&metrics.iter().map(|m| m.speed).collect::<Vec<_>>());
+    println!(
+        "  {} summary: total_time min={:.2}s median={:.2}s max={:.2}s mean={:.2}s | speed mean={:.2} MB/s",
+        package, total.min, total.median, total.max, total.mean, speed.mean / 1_000_000.0
+    );
+}
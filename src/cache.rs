@@ -1,12 +1,208 @@
-use anyhow::Result;
+use anyhow::{Context, Result};
+use rusqlite::{params, Connection};
+use sha2::{Digest, Sha256};
 use std::fs;
 use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
 
-pub fn cache_package(pkg_name: &str, filename: &str) -> Result<PathBuf> {
-    let cache_dir = dirs::cache_dir().unwrap_or_else(|| PathBuf::from(".mint_cache"));
-    fs::create_dir_all(&cache_dir)?;
-    let dest = cache_dir.join(filename);
-    fs::copy(filename, &dest)?;
-    println!("✅ Cached {} at {:?}", pkg_name, dest);
+fn cache_root() -> PathBuf {
+    dirs::cache_dir()
+        .unwrap_or_else(|| PathBuf::from(".mint_cache"))
+        .join("mint")
+}
+
+fn blobs_dir() -> PathBuf {
+    cache_root().join("blobs")
+}
+
+fn index_path() -> PathBuf {
+    cache_root().join("index.sqlite3")
+}
+
+fn open_index() -> Result<Connection> {
+    fs::create_dir_all(cache_root())?;
+    let conn = Connection::open(index_path())?;
+    conn.execute(
+        "CREATE TABLE IF NOT EXISTS cache_entries (
+            package    TEXT NOT NULL,
+            version    TEXT NOT NULL,
+            wheel_tag  TEXT NOT NULL,
+            hash       TEXT NOT NULL,
+            size       INTEGER NOT NULL,
+            url        TEXT NOT NULL,
+            fetched_at INTEGER NOT NULL,
+            PRIMARY KEY (package, version, wheel_tag)
+        )",
+        [],
+    )?;
+    Ok(conn)
+}
+
+fn sha256_hex(data: &[u8]) -> String {
+    let digest = Sha256::digest(data);
+    format!("{:x}", digest)
+}
+
+fn blob_path(hash: &str) -> PathBuf {
+    blobs_dir().join(&hash[..2]).join(hash)
+}
+
+fn now_unix() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+/// Check the index before doing any network work; returns the cached blob path on a hit.
+pub fn lookup(package: &str, version: &str, wheel_tag: &str) -> Result<Option<PathBuf>> {
+    let conn = open_index()?;
+    let hash: Option<String> = conn
+        .query_row(
+            "SELECT hash FROM cache_entries WHERE package = ?1 AND version = ?2 AND wheel_tag = ?3",
+            params![package, version, wheel_tag],
+            |row| row.get(0),
+        )
+        .ok();
+
+    match hash {
+        Some(hash) => {
+            let path = blob_path(&hash);
+            if path.exists() {
+                Ok(Some(path))
+            } else {
+                Ok(None)
+            }
+        }
+        None => Ok(None),
+    }
+}
+
+/// Store a downloaded artifact content-addressed by its sha256, and record it in the index.
+/// `filename` is the path to the just-downloaded file on disk (not yet moved into the cache).
+/// When `expected_sha256` is `Some` (the resolver pulled a digest from PyPI's release metadata),
+/// the file's hash is checked against it before anything is trusted into the cache - this is the
+/// last line of defense even though `downloader::download_package` already verifies the same
+/// digest as the bytes stream in.
+pub fn cache_package(
+    package: &str,
+    version: &str,
+    wheel_tag: &str,
+    filename: &str,
+    url: &str,
+    expected_sha256: Option<&str>,
+) -> Result<PathBuf> {
+    let data = fs::read(filename).with_context(|| format!("failed to read {}", filename))?;
+    let hash = sha256_hex(&data);
+    if let Some(expected) = expected_sha256 {
+        verify_digest(&data, expected)?;
+    }
+    let dest = blob_path(&hash);
+
+    if let Some(parent) = dest.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    if !dest.exists() {
+        fs::write(&dest, &data)?;
+    }
+
+    let conn = open_index()?;
+    conn.execute(
+        "INSERT OR REPLACE INTO cache_entries (package, version, wheel_tag, hash, size, url, fetched_at)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+        params![package, version, wheel_tag, hash, data.len() as i64, url, now_unix() as i64],
+    )?;
+
+    println!("✅ Cached {} {} at {:?}", package, version, dest);
     Ok(dest)
 }
+
+/// Verify `data`'s sha256 against PyPI's `digests.sha256` before it's trusted into the cache.
+pub fn verify_digest(data: &[u8], expected_sha256: &str) -> Result<(), crate::error::Error> {
+    let actual = sha256_hex(data);
+    if !actual.eq_ignore_ascii_case(expected_sha256) {
+        return Err(crate::error::Error::HashMismatch {
+            expected: expected_sha256.to_string(),
+            actual,
+        });
+    }
+    Ok(())
+}
+
+/// Remove every blob not referenced by any index row.
+pub fn gc() -> Result<()> {
+    let conn = open_index()?;
+    let mut stmt = conn.prepare("SELECT hash FROM cache_entries")?;
+    let referenced: std::collections::HashSet<String> = stmt
+        .query_map([], |row| row.get::<_, String>(0))?
+        .filter_map(|r| r.ok())
+        .collect();
+    drop(stmt);
+
+    let dir = blobs_dir();
+    if !dir.exists() {
+        println!("✅ Cache already clean");
+        return Ok(());
+    }
+
+    let mut removed = 0;
+    for shard in fs::read_dir(&dir)? {
+        let shard = shard?;
+        if !shard.path().is_dir() {
+            continue;
+        }
+        for entry in fs::read_dir(shard.path())? {
+            let entry = entry?;
+            let hash = entry.file_name().to_string_lossy().to_string();
+            if !referenced.contains(&hash) {
+                fs::remove_file(entry.path())?;
+                removed += 1;
+            }
+        }
+    }
+
+    println!("✅ Pruned {} unreferenced blob(s)", removed);
+    Ok(())
+}
+
+/// Best-effort wheel tag extraction from a filename, for indexing purposes only.
+pub fn wheel_tag_from_filename(filename: &str) -> String {
+    let stem = Path::new(filename)
+        .file_stem()
+        .and_then(|s| s.to_str())
+        .unwrap_or(filename);
+    let parts: Vec<&str> = stem.split('-').collect();
+    if parts.len() >= 5 {
+        parts[2..].join("-")
+    } else {
+        "unknown".to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn verify_digest_accepts_matching_hash() {
+        let data = b"hello world";
+        let expected = sha256_hex(data);
+        assert!(verify_digest(data, &expected).is_ok());
+    }
+
+    #[test]
+    fn verify_digest_rejects_mismatch() {
+        let data = b"hello world";
+        let err = verify_digest(data, "0000000000000000000000000000000000000000000000000000000000000000").unwrap_err();
+        assert!(matches!(err, crate::error::Error::HashMismatch { .. }));
+    }
+
+    #[test]
+    fn wheel_tag_from_filename_extracts_tag() {
+        assert_eq!(
+            wheel_tag_from_filename("requests-2.31.0-py3-none-any.whl"),
+            "py3-none-any"
+        );
+        assert_eq!(wheel_tag_from_filename("not-a-wheel"), "unknown");
+    }
+}
@@ -1,9 +1,147 @@
-use clap::{Parser, Subcommand};
-use crate::{resolver, downloader, installer, cache};
+use clap::{Parser, Subcommand, ValueEnum};
+use crate::dependency::Dependency;
+use crate::{downloader, installer, cache, resolver};
 use reqwest::Client;
 use tokio::task;
 use anyhow::Result;
-use futures::stream::{FuturesUnordered, StreamExt};
+use futures_util::stream::{FuturesUnordered, StreamExt};
+use std::collections::HashSet;
+use std::path::PathBuf;
+use std::sync::Arc;
+use tracing::{info, error, warn};
+use std::fs;
+
+/// Interpreter version used to evaluate environment markers until `mint` can detect the active
+/// interpreter itself.
+const DEFAULT_PYTHON_VERSION: &str = "3.11";
+/// Default path `mint lock`/`mint sync` read and write.
+const DEFAULT_LOCKFILE_PATH: &str = "mint.lock";
+
+/// Resolve `package` (honoring its version constraint, extras, and environment markers) plus
+/// its full transitive closure, then download and install every package in the resolved set.
+/// `mint` no longer installs with `--no-deps`: a bad transitive dependency surfaces here instead
+/// of silently leaving the environment broken. Returns the full resolved set (not just what was
+/// actually installed) so the caller can fold it into a lock file.
+async fn install_package(
+    client: &Arc<Client>,
+    package: &str,
+    venv_path: Option<&str>,
+    force: bool,
+    require_hashes: bool,
+) -> Result<Vec<Dependency>> {
+    info!("Resolving: {}", package);
+
+    let config = crate::config::Config::load()?;
+    let (pkg_name, extras, constraint) = parse_spec(package);
+    let resolved = crate::dependency::resolve_dependencies(
+        &pkg_name,
+        &extras,
+        &constraint,
+        DEFAULT_PYTHON_VERSION,
+        &config.trusted_hosts,
+        require_hashes,
+        client,
+    )
+    .await?;
+    info!("Resolved {} package(s) for {}", resolved.len(), package);
+
+    let installed = crate::site_packages::scan(venv_path)?;
+    let plan = crate::dependency::plan_install(resolved.clone(), &installed, force);
+    if !plan.already_satisfied.is_empty() {
+        info!(
+            "Already satisfied, skipping: {}",
+            plan.already_satisfied.iter().map(|d| format!("{}=={}", d.name, d.version)).collect::<Vec<_>>().join(", ")
+        );
+    }
+
+    download_and_install(client, plan.install.into_iter().chain(plan.reinstall).collect(), venv_path).await?;
+
+    info!("Successfully installed {}", package);
+    Ok(resolved)
+}
+
+/// Download (retrying up to 3 times, verifying each `sha256` when one was resolved) and install
+/// every dependency in `deps`, reusing a content-addressed cache hit instead of redownloading
+/// whatever `cache::lookup` already has on disk. Shared by `install`'s plan execution and `sync`'s.
+async fn download_and_install(client: &Arc<Client>, deps: Vec<Dependency>, venv_path: Option<&str>) -> Result<()> {
+    for dep in deps {
+        let filename = dep.source.split('/').next_back().unwrap_or(&dep.name).to_string();
+        let wheel_tag = cache::wheel_tag_from_filename(&filename);
+
+        let cached_path = match cache::lookup(&dep.name, &dep.version, &wheel_tag)? {
+            Some(hit) => hit,
+            None => {
+                for attempt in 1..=3 {
+                    if downloader::download_package(client, &dep.source, &filename, dep.sha256.as_deref()).await.is_ok() {
+                        break;
+                    } else if attempt == 3 {
+                        anyhow::bail!("Failed to download {} after 3 attempts", filename);
+                    } else {
+                        warn!("Download attempt {}/3 failed for {}", attempt, filename);
+                    }
+                }
+                cache::cache_package(&dep.name, &dep.version, &wheel_tag, &filename, &dep.source, dep.sha256.as_deref())?
+            }
+        };
+        installer::install_wheel(cached_path.to_str().unwrap_or(""), venv_path)?;
+    }
+    Ok(())
+}
+
+/// Split a CLI package spec like `requests[security]>=2.28,<3` into its bare name, requested
+/// extras, and root version constraint, so the resolver can seed the solver with the same
+/// pin the user typed instead of always grabbing the newest release.
+fn parse_spec(spec: &str) -> (String, Vec<String>, crate::version::VersionSet) {
+    let (name_and_extras, constraint_part) = match spec.find(['=', '>', '<', '!', '~']) {
+        Some(idx) => (&spec[..idx], &spec[idx..]),
+        None => (spec, ""),
+    };
+
+    let (name, extras) = match name_and_extras.split_once('[') {
+        Some((n, rest)) => {
+            let extras = rest
+                .trim_end_matches(']')
+                .split(',')
+                .map(|e| e.trim().to_string())
+                .filter(|e| !e.is_empty())
+                .collect();
+            (n.trim().to_string(), extras)
+        }
+        None => (name_and_extras.trim().to_string(), Vec::new()),
+    };
+
+    (name, extras, crate::version::VersionSet::parse(constraint_part))
+}
+
+/// Resolve a `--python` request (full path, or version like `3.11`) to an executable path,
+/// falling back to bootstrapping a python-build-standalone release when nothing local matches.
+async fn resolve_python(request: &str, client: &Arc<Client>) -> Result<String> {
+    if let Some(key) = crate::python::find_best_match(request) {
+        return Ok(key.path.to_string_lossy().to_string());
+    }
+    warn!("No local interpreter satisfies --python {}, bootstrapping a standalone build", request);
+    let path = crate::python::bootstrap_standalone(request, client).await?;
+    Ok(path.to_string_lossy().to_string())
+}
+
+/// How `mint sync` should treat versions already pinned in the lock file.
+///
+/// Each re-resolved package is treated as its own root: the lock file doesn't currently record
+/// which entries were originally top-level requests versus pulled in transitively, nor any
+/// extras they were requested with, so re-solving `--upgrade all` or a named `--upgrade-package`
+/// re-resolves transitive entries too (redundantly re-walking shared subtrees) with no extras
+/// and no visibility into another lock entry's constraint on a dependency they share - a later
+/// re-resolve can overwrite an earlier one's pin, or drop an extras-only dependency, without
+/// rechecking it. Re-run `mint lock` from the original package list (with extras) for a fully
+/// consistent re-solve.
+#[derive(Clone, Debug, ValueEnum)]
+pub enum Upgrade {
+    /// Keep every pinned lock version as-is; `--upgrade-package` may still re-resolve
+    /// individually named packages.
+    None,
+    /// Re-resolve every package in the lock to its newest allowed version before syncing.
+    All,
+}
 
 #[derive(Parser)]
 #[command(name = "mint")]
@@ -15,54 +153,482 @@ pub struct Cli {
 
 #[derive(Subcommand)]
 pub enum Commands {
-    PipInstall { packages: Vec<String> },
-    PipUninstall { packages: Vec<String> },
-    VenvCreate { name: String },
-    Run { venv: String, script: String },
+    /// Install packages (supports package==version, >=1.0.0, etc.)
+    Install {
+        packages: Vec<String>,
+        /// Virtual environment path
+        #[arg(short = 'v', long = "venv")]
+        venv: Option<String>,
+        /// Force reinstall packages
+        #[arg(short = 'f', long = "force")]
+        force: bool,
+        /// Install development dependencies
+        #[arg(short = 'd', long = "dev")]
+        dev: bool,
+        /// Number of parallel downloads
+        #[arg(short = 'j', long = "jobs")]
+        jobs: Option<usize>,
+        /// Refuse to install any package whose index metadata lacks a sha256 digest
+        #[arg(long = "require-hashes")]
+        require_hashes: bool,
+        /// Fold the resolved set into mint.lock after a successful install
+        #[arg(long = "save-lock")]
+        save_lock: bool,
+    },
+    /// Uninstall packages
+    Uninstall {
+        packages: Vec<String>,
+        /// Virtual environment path
+        #[arg(short = 'v', long = "venv")]
+        venv: Option<String>,
+        /// Confirm uninstallation
+        #[arg(short = 'y', long = "yes")]
+        yes: bool,
+    },
+    /// Create a virtual environment
+    VenvCreate {
+        name: String,
+        /// Python version to use
+        #[arg(short = 'p', long = "python")]
+        python: Option<String>,
+    },
+    /// Delete a virtual environment
+    VenvDelete {
+        name: String,
+        /// Force deletion without confirmation
+        #[arg(short = 'f', long = "force")]
+        force: bool,
+    },
+    /// Run a Python script in virtual environment
+    Run {
+        venv: String,
+        script: String,
+        /// Pass arguments to the script
+        #[arg(short = 'a', long = "args")]
+        args: Vec<String>,
+    },
+    /// List installed packages
+    List {
+        /// Virtual environment path
+        #[arg(short = 'v', long = "venv")]
+        venv: Option<String>,
+        /// Show outdated packages
+        #[arg(short = 'o', long = "outdated")]
+        outdated: bool,
+    },
+    /// Show package information
+    Show {
+        package: String,
+        /// Virtual environment path
+        #[arg(short = 'v', long = "venv")]
+        venv: Option<String>,
+    },
+    /// Search for packages
+    Search {
+        query: String,
+        /// Limit results
+        #[arg(short = 'l', long = "limit")]
+        limit: Option<usize>,
+    },
+    /// Prune cached blobs no longer referenced by the content-addressed cache index.
+    CacheClean,
+    /// Show cache information
+    CacheInfo,
+    /// Install from requirements.txt file
+    InstallRequirements {
+        /// Path to requirements.txt file
+        #[arg(short = 'r', long = "requirements")]
+        requirements: Option<String>,
+        /// Virtual environment path
+        #[arg(short = 'v', long = "venv")]
+        venv: Option<String>,
+        /// Refuse to install any package whose index metadata lacks a sha256 digest
+        #[arg(long = "require-hashes")]
+        require_hashes: bool,
+    },
+    /// Generate requirements.txt from installed packages
+    Freeze {
+        /// Output file path
+        #[arg(short = 'o', long = "output")]
+        output: Option<String>,
+        /// Virtual environment path
+        #[arg(short = 'v', long = "venv")]
+        venv: Option<String>,
+    },
+    /// Resolve packages' full transitive closure once and write mint.lock
+    Lock {
+        packages: Vec<String>,
+        /// Virtual environment path (used to evaluate the target interpreter, not installed into)
+        #[arg(short = 'v', long = "venv")]
+        venv: Option<String>,
+        /// Lock file path
+        #[arg(short = 'o', long = "output")]
+        output: Option<String>,
+    },
+    /// Reconcile a venv to exactly match mint.lock: install what's missing and uninstall
+    /// anything present that the lock file no longer knows about.
+    Sync {
+        /// Virtual environment path
+        #[arg(short = 'v', long = "venv")]
+        venv: Option<String>,
+        /// Lock file path
+        #[arg(short = 'l', long = "lockfile")]
+        lockfile: Option<String>,
+        /// Force-reinstall every locked package even if the version already matches.
+        #[arg(long = "reinstall")]
+        reinstall: bool,
+        /// Control how pinned lock versions are refreshed before syncing.
+        #[arg(long = "upgrade", value_enum, default_value = "none")]
+        upgrade: Upgrade,
+        /// Only re-resolve these named packages before syncing (ignored when `--upgrade all`
+        /// is set, since that already re-resolves everything).
+        #[arg(long = "upgrade-package")]
+        upgrade_packages: Vec<String>,
+    },
+    /// Run a benchmark workload file end-to-end (resolve + download + install).
+    Bench {
+        workload: PathBuf,
+        /// POST the raw results as JSON to this endpoint for regression tracking.
+        #[arg(long = "report-url")]
+        report_url: Option<String>,
+    },
 }
 
 pub async fn run() -> Result<()> {
     let cli = Cli::parse();
-    let client = Client::new();
+    let client = Arc::new(Client::new());
 
     match cli.command {
-        Commands::PipInstall { packages } => {
-            let mut futures = FuturesUnordered::new();
+        Commands::Install { packages, venv, force, dev: _dev, jobs, require_hashes, save_lock } => {
+            info!("Installing packages: {:?}", packages);
+            let max_jobs = jobs.unwrap_or_else(num_cpus::get);
+            info!("Using {} parallel jobs", max_jobs);
 
-            for pkg in packages {
-                let c = client.clone();
+            let packages_count = packages.len();
+            let mut top = FuturesUnordered::new();
+            for pkg in &packages {
+                let c = Arc::clone(&client);
                 let pkg_clone = pkg.clone();
+                let venv_clone = venv.clone();
+                top.push(task::spawn(async move {
+                    install_package(&c, &pkg_clone, venv_clone.as_deref(), force, require_hashes).await
+                }));
+            }
+
+            let mut completed = 0;
+            let mut all_resolved = Vec::new();
+            while let Some(result) = top.next().await {
+                match result {
+                    Ok(Ok(resolved)) => {
+                        completed += 1;
+                        all_resolved.extend(resolved);
+                        info!("Completed installation {}/{}", completed, packages_count);
+                    }
+                    Ok(Err(e)) => error!("Installation failed: {}", e),
+                    Err(e) => error!("Task failed: {}", e),
+                }
+            }
+
+            if save_lock {
+                let path = PathBuf::from(DEFAULT_LOCKFILE_PATH);
+                let mut lock = crate::dependency::LockFile::load(&path)?;
+                for dep in all_resolved {
+                    lock.add_package(dep);
+                }
+                lock.save(&path)?;
+                info!("Updated {:?} with {} packages", path, lock.packages.len());
+            }
+        }
+
+        Commands::Uninstall { packages, venv, yes } => {
+            for pkg in packages {
+                if !yes {
+                    println!("Are you sure you want to uninstall {}? (y/N)", pkg);
+                    let mut input = String::new();
+                    std::io::stdin().read_line(&mut input)?;
+                    if !input.trim().to_lowercase().starts_with('y') {
+                        info!("Skipping uninstall of {}", pkg);
+                        continue;
+                    }
+                }
+
+                let v = venv.as_deref();
+                if let Err(e) = installer::uninstall_package(&pkg, v) {
+                    error!("Failed to uninstall {}: {}", pkg, e);
+                } else {
+                    info!("Successfully uninstalled {}", pkg);
+                }
+            }
+        }
+
+        Commands::VenvCreate { name, python } => {
+            info!("Creating virtual environment: {}", name);
+            let python_bin = match &python {
+                Some(request) => Some(resolve_python(request, &client).await?),
+                None => None,
+            };
+            installer::create_venv(&name, python_bin.as_deref())?;
+
+            if let Some(request) = python {
+                let mut config = crate::config::Config::load()?;
+                config.default_python = Some(request);
+                config.save()?;
+            }
+            info!("Successfully created virtual environment: {}", name);
+        }
+
+        Commands::VenvDelete { name, force } => {
+            if !force {
+                println!("Are you sure you want to delete virtual environment {}? (y/N)", name);
+                let mut input = String::new();
+                std::io::stdin().read_line(&mut input)?;
+                if !input.trim().to_lowercase().starts_with('y') {
+                    info!("Skipping deletion of {}", name);
+                    return Ok(());
+                }
+            }
+
+            std::fs::remove_dir_all(&name)?;
+            info!("Successfully deleted virtual environment: {}", name);
+        }
 
-                futures.push(task::spawn(async move {
-                    let meta = resolver::fetch_package_metadata(&c, &pkg_clone).await.unwrap();
-
-                    if let Some(releases) = meta.releases() {
-                        if let Some(versions) = releases.values().last() {
-                            if let Some(file) = versions[0].as_object() {
-                                if let Some(url_str) = file.get("url").and_then(|u| u.as_str()) {
-                                    let filename = url_str.split('/').last().unwrap();
-                                    downloader::download_package(&c, url_str, filename).await.unwrap();
-                                    let cached = cache::cache_package(&pkg_clone, filename).unwrap();
-                                    installer::install_wheel(cached.to_str().unwrap()).unwrap();
+        Commands::Run { venv, script, args } => {
+            let full_script = if args.is_empty() {
+                script
+            } else {
+                format!("{} {}", script, args.join(" "))
+            };
+            installer::run_in_venv(&venv, &full_script)?;
+        }
+
+        Commands::List { venv, outdated } => {
+            let installed = crate::site_packages::scan(venv.as_deref())?;
+            let mut names: Vec<&String> = installed.keys().collect();
+            names.sort();
+
+            for name in names {
+                let pkg = &installed[name];
+                if outdated {
+                    match resolver::fetch_package_metadata(&client, &pkg.name).await {
+                        Ok(meta) => {
+                            let latest = meta
+                                .releases()
+                                .and_then(|r| r.keys().filter_map(|v| crate::version::Version::parse(v).map(|p| (p, v.clone()))).max_by(|a, b| a.0.cmp(&b.0)))
+                                .map(|(_, v)| v);
+                            match latest {
+                                Some(latest) if latest != pkg.version => {
+                                    println!("{}=={} (latest: {})", pkg.name, pkg.version, latest)
                                 }
+                                _ => {}
                             }
                         }
+                        Err(e) => warn!("Failed to check latest version for {}: {}", pkg.name, e),
                     }
-                }));
+                } else {
+                    println!("{}=={}", pkg.name, pkg.version);
+                }
+            }
+        }
+
+        Commands::Show { package, venv } => {
+            let installed = crate::site_packages::scan(venv.as_deref())?;
+            match installed.get(&crate::site_packages::normalize(&package)) {
+                Some(pkg) => {
+                    for key in ["Name", "Version", "Summary", "Home-page", "Author", "License", "Requires-Python"] {
+                        if let Some(value) = pkg.fields.get(key) {
+                            println!("{}: {}", key, value);
+                        }
+                    }
+                }
+                None => println!("Package {} is not installed", package),
             }
+        }
 
-            while let Some(_) = futures.next().await {}
+        Commands::Search { query, limit: _limit } => {
+            info!("Searching for packages: {}", query);
+            // TODO: Implement package search
+            println!("Package search not yet implemented");
         }
-        Commands::PipUninstall { packages } => {
-            for pkg in packages {
-                installer::uninstall_package(&pkg)?;
+
+        Commands::CacheClean => {
+            info!("Pruning unreferenced cache blobs");
+            cache::gc()?;
+        }
+
+        Commands::CacheInfo => {
+            info!("Showing cache information");
+            // TODO: Implement cache info display
+            println!("Cache information display not yet implemented");
+        }
+
+        Commands::InstallRequirements { requirements, venv, require_hashes } => {
+            let req_path = requirements.unwrap_or_else(|| "requirements.txt".to_string());
+            let path = std::path::PathBuf::from(&req_path);
+
+            match crate::requirements::parse_requirements(&path) {
+                Ok(packages) => {
+                    info!("Installing {} packages from requirements file", packages.len());
+
+                    let _max_jobs = num_cpus::get();
+                    let mut top = FuturesUnordered::new();
+
+                    for pkg in &packages {
+                        let c = Arc::clone(&client);
+                        let pkg_clone = pkg.clone();
+                        let venv_clone = venv.clone();
+                        top.push(task::spawn(async move {
+                            install_package(&c, &pkg_clone, venv_clone.as_deref(), false, require_hashes).await
+                        }));
+                    }
+
+                    let mut completed = 0;
+                    while let Some(result) = top.next().await {
+                        match result {
+                            Ok(Ok(_)) => {
+                                completed += 1;
+                                info!("Completed installation {}/{}", completed, packages.len());
+                            }
+                            Ok(Err(e)) => error!("Installation failed: {}", e),
+                            Err(e) => error!("Task failed: {}", e),
+                        }
+                    }
+                }
+                Err(e) => error!("Failed to parse requirements file: {}", e),
             }
         }
-        Commands::VenvCreate { name } => {
-            installer::create_venv(&name)?;
+
+        Commands::Freeze { output, venv } => {
+            match crate::requirements::generate_requirements(venv.as_deref()) {
+                Ok(requirements) => {
+                    if let Some(output_path) = output {
+                        let path = std::path::PathBuf::from(output_path);
+                        fs::write(&path, requirements)?;
+                        info!("Requirements saved to {:?}", path);
+                    } else {
+                        print!("{}", requirements);
+                    }
+                }
+                Err(e) => error!("Failed to generate requirements: {}", e),
+            }
+        }
+
+        Commands::Lock { packages, venv: _venv, output } => {
+            let config = crate::config::Config::load()?;
+            let mut lock = crate::dependency::LockFile::new(DEFAULT_PYTHON_VERSION.to_string());
+
+            for pkg in &packages {
+                let (pkg_name, extras, constraint) = parse_spec(pkg);
+                let resolved = crate::dependency::resolve_dependencies(
+                    &pkg_name,
+                    &extras,
+                    &constraint,
+                    DEFAULT_PYTHON_VERSION,
+                    &config.trusted_hosts,
+                    false,
+                    &client,
+                )
+                .await?;
+                info!("Resolved {} package(s) for {}", resolved.len(), pkg);
+                for dep in resolved {
+                    lock.add_package(dep);
+                }
+            }
+
+            let path = PathBuf::from(output.unwrap_or_else(|| DEFAULT_LOCKFILE_PATH.to_string()));
+            lock.save(&path)?;
+            info!("Wrote {:?} with {} packages", path, lock.packages.len());
         }
-        Commands::Run { venv, script } => {
-            installer::run_in_venv(&venv, &script)?;
+
+        Commands::Sync { venv, lockfile, reinstall, upgrade, upgrade_packages } => {
+            let path = PathBuf::from(lockfile.unwrap_or_else(|| DEFAULT_LOCKFILE_PATH.to_string()));
+            if !path.exists() {
+                anyhow::bail!("Lock file {:?} not found; run `mint lock` first", path);
+            }
+            let mut lock = crate::dependency::LockFile::load(&path)?;
+
+            let mut to_reresolve: Vec<String> = match upgrade {
+                Upgrade::All => lock.packages.keys().cloned().collect(),
+                Upgrade::None => upgrade_packages,
+            };
+            // Sorted so `--upgrade all`'s re-resolve order (and thus which named package's pin
+            // of a shared transitive dependency wins in the lock) is reproducible run-to-run,
+            // rather than following the lock file's HashMap's unspecified iteration order.
+            to_reresolve.sort();
+
+            if !to_reresolve.is_empty() {
+                let config = crate::config::Config::load()?;
+                // Bounded, unlike a bare `FuturesUnordered` fan-out: re-resolving every entry in
+                // a large lock file would otherwise open one simultaneous PyPI request per
+                // package with no limit.
+                let max_jobs = config.parallel_downloads.filter(|&n| n > 0).unwrap_or_else(num_cpus::get);
+                let semaphore = Arc::new(tokio::sync::Semaphore::new(max_jobs));
+                let python_version = lock.metadata.python_version.clone();
+                let mut pending = FuturesUnordered::new();
+                for (index, pkg) in to_reresolve.iter().enumerate() {
+                    let c = Arc::clone(&client);
+                    let pkg_clone = pkg.clone();
+                    let trusted_hosts = config.trusted_hosts.clone();
+                    let permit = Arc::clone(&semaphore);
+                    let python_version = python_version.clone();
+                    pending.push(task::spawn(async move {
+                        let _permit = permit.acquire_owned().await.expect("semaphore never closed");
+                        let (pkg_name, extras, constraint) = parse_spec(&pkg_clone);
+                        let resolved = crate::dependency::resolve_dependencies(
+                            &pkg_name,
+                            &extras,
+                            &constraint,
+                            &python_version,
+                            &trusted_hosts,
+                            false,
+                            &c,
+                        )
+                        .await;
+                        (index, pkg_clone, resolved)
+                    }));
+                }
+
+                // Resolve concurrently, but apply each package's pins to the lock in the fixed
+                // `to_reresolve` order (not completion order) so a shared transitive dependency
+                // re-pinned by two different packages lands on the same winner every run.
+                let mut by_index = vec![None; to_reresolve.len()];
+                while let Some(result) = pending.next().await {
+                    let (index, pkg, resolved) = result?;
+                    by_index[index] = Some((pkg, resolved?));
+                }
+                for (pkg, resolved) in by_index.into_iter().flatten() {
+                    info!("Re-resolved {} package(s) for {}", resolved.len(), pkg);
+                    for dep in resolved {
+                        lock.add_package(dep);
+                    }
+                }
+                lock.save(&path)?;
+            }
+
+            let installed = crate::site_packages::scan(venv.as_deref())?;
+            let resolved: Vec<Dependency> = lock.packages.values().cloned().collect();
+            let plan = crate::dependency::plan_install(resolved, &installed, reinstall);
+            if !plan.already_satisfied.is_empty() {
+                info!(
+                    "Already in sync: {}",
+                    plan.already_satisfied.iter().map(|d| format!("{}=={}", d.name, d.version)).collect::<Vec<_>>().join(", ")
+                );
+            }
+
+            download_and_install(&client, plan.install.into_iter().chain(plan.reinstall).collect(), venv.as_deref()).await?;
+
+            let locked: HashSet<String> = lock.packages.keys().map(|name| crate::site_packages::normalize(name)).collect();
+            for (normalized, pkg) in &installed {
+                if !locked.contains(normalized) {
+                    info!("Removing {} (not present in lock file)", pkg.name);
+                    installer::uninstall_package(&pkg.name, venv.as_deref())?;
+                }
+            }
+
+            info!("Synced venv to {:?}", path);
+        }
+
+        Commands::Bench { workload, report_url } => {
+            crate::benchmark::run_workload(&client, &workload, report_url.as_deref()).await?;
         }
     }
 
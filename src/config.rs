@@ -56,7 +56,7 @@ impl Config {
 
     fn config_path() -> Result<PathBuf> {
         let mut path = dirs::config_dir()
-            .or_else(|| dirs::home_dir())
+            .or_else(dirs::home_dir)
             .unwrap_or_else(|| PathBuf::from("."));
         path.push(".mint");
         path.push("config.toml");
@@ -0,0 +1,506 @@
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::path::PathBuf;
+use std::sync::Arc;
+use tracing::{info, warn};
+
+use crate::resolver::{self, PyPiResponse, ReleaseFile};
+use crate::site_packages::{self, InstalledPackage};
+use crate::version::{LocalSegment, Version, VersionSet};
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct Dependency {
+    pub name: String,
+    pub version: String,
+    pub source: String,
+    pub dependencies: Vec<Dependency>,
+    /// Expected `sha256` digest for `source`, when PyPI published one. `None` means the index
+    /// didn't provide a digest for this file (only possible when `--require-hashes` is off).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sha256: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct LockFile {
+    pub packages: HashMap<String, Dependency>,
+    pub metadata: LockFileMetadata,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct LockFileMetadata {
+    pub version: String,
+    pub python_version: String,
+    pub generated_at: String,
+    pub mint_version: String,
+}
+
+impl LockFile {
+    pub fn new(python_version: String) -> Self {
+        Self {
+            packages: HashMap::new(),
+            metadata: LockFileMetadata {
+                version: "1.0".to_string(),
+                python_version,
+                generated_at: chrono::Utc::now().to_rfc3339(),
+                mint_version: env!("CARGO_PKG_VERSION").to_string(),
+            },
+        }
+    }
+
+    pub fn load(path: &PathBuf) -> Result<Self> {
+        if path.exists() {
+            let content = fs::read_to_string(path)?;
+            let lockfile: LockFile = toml::from_str(&content)?;
+            info!("Loaded lock file with {} packages", lockfile.packages.len());
+            Ok(lockfile)
+        } else {
+            warn!("Lock file not found, creating new one");
+            Ok(LockFile::new("unknown".to_string()))
+        }
+    }
+
+    pub fn save(&self, path: &PathBuf) -> Result<()> {
+        let content = toml::to_string_pretty(self)?;
+        fs::write(path, content)?;
+        info!("Saved lock file with {} packages", self.packages.len());
+        Ok(())
+    }
+
+    pub fn add_package(&mut self, dep: Dependency) {
+        self.packages.insert(dep.name.clone(), dep);
+    }
+}
+
+/// A clause contributed by one package's requirement, so a conflict can be traced back to
+/// whichever decision introduced it instead of unwinding the whole search on every failure.
+#[derive(Clone)]
+struct Clause {
+    constraint: VersionSet,
+}
+
+/// One resolved pin plus the decision level it was made at, so a conflict can backjump straight
+/// to the decision that caused it rather than naively backtracking one step at a time.
+struct Decision {
+    package: String,
+    level: usize,
+}
+
+/// Builds a complete, conflict-free version set before any download happens. This is a
+/// pragmatic, PubGrub-flavored solver: it keeps a partial solution of package->version
+/// assignments plus the accumulated constraint ("incompatibility") clauses behind each one, and
+/// on conflict backjumps to the decision that introduced the offending clause instead of
+/// restarting from scratch.
+struct Solver<'a> {
+    client: &'a Arc<reqwest::Client>,
+    python_version: String,
+    trusted_hosts: Vec<String>,
+    require_hashes: bool,
+    cache: HashMap<String, PyPiResponse>,
+    clauses: HashMap<String, Vec<Clause>>,
+    requested_extras: HashMap<String, Vec<String>>,
+    assignments: HashMap<String, String>,
+    selected_files: HashMap<String, ReleaseFile>,
+    excluded: HashMap<String, HashSet<String>>,
+    decisions: Vec<Decision>,
+}
+
+impl<'a> Solver<'a> {
+    fn new(client: &'a Arc<reqwest::Client>, python_version: &str, trusted_hosts: Vec<String>, require_hashes: bool) -> Self {
+        Self {
+            client,
+            python_version: python_version.to_string(),
+            trusted_hosts,
+            require_hashes,
+            cache: HashMap::new(),
+            clauses: HashMap::new(),
+            requested_extras: HashMap::new(),
+            assignments: HashMap::new(),
+            selected_files: HashMap::new(),
+            excluded: HashMap::new(),
+            decisions: Vec::new(),
+        }
+    }
+
+    async fn metadata(&mut self, name: &str) -> Result<PyPiResponse> {
+        if !self.cache.contains_key(name) {
+            let meta = resolver::fetch_package_metadata(self.client, name).await?;
+            self.cache.insert(name.to_string(), meta);
+        }
+        Ok(self.cache.get(name).unwrap().clone())
+    }
+
+    fn merged_constraint(&self, name: &str) -> VersionSet {
+        let mut reqs = Vec::new();
+        if let Some(clauses) = self.clauses.get(name) {
+            for clause in clauses {
+                reqs.extend(clause.constraint.reqs.clone());
+            }
+        }
+        VersionSet { reqs }
+    }
+
+    fn decision_level_for(&self, package: &str) -> usize {
+        self.decisions
+            .iter()
+            .find(|d| d.package == package)
+            .map(|d| d.level)
+            .unwrap_or(0)
+    }
+
+    /// Undo every decision made after `level`: unit propagation only ever needs to redo the
+    /// part of the partial solution downstream of the conflicting one.
+    fn backjump_to(&mut self, level: usize) {
+        while let Some(last) = self.decisions.last() {
+            if last.level <= level {
+                break;
+            }
+            let decision = self.decisions.pop().unwrap();
+            self.assignments.remove(&decision.package);
+        }
+    }
+
+    /// Resolve `root` (honoring `requested_extras`) and its full transitive closure into a flat
+    /// list `run()` can feed straight into the existing parallel download loop.
+    async fn resolve(&mut self, root: &str, requested_extras: &[String], root_constraint: VersionSet) -> Result<Vec<Dependency>> {
+        self.requested_extras.insert(root.to_string(), requested_extras.to_vec());
+
+        let mut queue: Vec<(String, VersionSet, usize)> = vec![(root.to_string(), root_constraint, 0)];
+        let mut guard = 0usize;
+        const MAX_ATTEMPTS: usize = 500;
+
+        while let Some((name, constraint, level)) = queue.pop() {
+            guard += 1;
+            if guard > MAX_ATTEMPTS {
+                anyhow::bail!("dependency resolution for '{}' did not converge after {} backjumps", root, MAX_ATTEMPTS);
+            }
+
+            self.clauses.entry(name.clone()).or_default().push(Clause { constraint });
+
+            if let Some(assigned) = self.assignments.get(&name).cloned() {
+                let merged = self.merged_constraint(&name);
+                if let Some(v) = Version::parse(&assigned) {
+                    if merged.satisfies(&v) {
+                        continue; // Already-visited package at a satisfying version: treat as resolved.
+                    }
+                }
+                // The newly merged constraint no longer fits the existing pin: derive the
+                // conflict, backjump to just before that package was decided, and retry.
+                let conflict_level = self.decision_level_for(&name);
+                self.excluded.entry(name.clone()).or_default().insert(assigned);
+                self.backjump_to(conflict_level.saturating_sub(1));
+                let retry_constraint = self.merged_constraint(&name);
+                queue.push((name, retry_constraint, level));
+                continue;
+            }
+
+            let meta = self.metadata(&name).await?;
+            let merged = self.merged_constraint(&name);
+            let excluded_versions = self.excluded.get(&name).cloned().unwrap_or_default();
+
+            let mut candidates: Vec<(Version, String)> = meta
+                .releases()
+                .map(|releases| {
+                    releases
+                        .keys()
+                        .filter(|v| !excluded_versions.contains(*v))
+                        .filter_map(|v| Version::parse(v).map(|parsed| (parsed, v.clone())))
+                        .collect()
+                })
+                .unwrap_or_default();
+            candidates.sort_by(|a, b| b.0.cmp(&a.0)); // prefer the highest version satisfying all clauses
+
+            // Among the satisfying candidates, skip any whose only files live on an untrusted
+            // host, any yanked release (unless the user pinned it with `==` exactly), and -
+            // under `--require-hashes` - any release PyPI didn't publish a sha256 digest for.
+            let mut chosen: Option<(String, ReleaseFile)> = None;
+            let mut newest_compatible: Option<String> = None;
+            for (v, version_str) in candidates.iter().filter(|(v, _)| merged.satisfies(v)) {
+                if newest_compatible.is_none() {
+                    newest_compatible = Some(version_str.clone());
+                }
+                let Some(file) = meta.select_file(version_str, &self.trusted_hosts) else {
+                    continue;
+                };
+                if file.yanked && !has_exact_pin(&merged, v) {
+                    continue;
+                }
+                if self.require_hashes && file.sha256.is_none() {
+                    continue;
+                }
+                chosen = Some((version_str.clone(), file));
+                break;
+            }
+
+            let (version_str, file) = match chosen {
+                Some(found) => found,
+                None => {
+                    // Distinguish an actual version conflict (nothing in the index satisfies the
+                    // accumulated constraint) from a policy exclusion (something does, but it's
+                    // untrusted/yanked/hashless) - the two need different diagnostics, since the
+                    // fix for one is loosening a constraint and the fix for the other is a
+                    // `--trusted-host`/`--require-hashes` flag or waiting out a yank.
+                    let Some(found) = newest_compatible else {
+                        return Err(crate::error::Error::VersionConflict {
+                            package: name.clone(),
+                            wanted: format_constraint(&merged),
+                            found: "none".to_string(),
+                        }
+                        .into());
+                    };
+                    anyhow::bail!(
+                        "no installable release of '{}' satisfies the accumulated constraints {} \
+                         (the newest compatible version, {}, was excluded for an untrusted host, \
+                         being yanked, or missing a hash)",
+                        name,
+                        format_constraint(&merged),
+                        found
+                    );
+                }
+            };
+
+            self.assignments.insert(name.clone(), version_str.clone());
+            self.selected_files.insert(name.clone(), file);
+            self.decisions.push(Decision {
+                package: name.clone(),
+                level: level + 1,
+            });
+
+            let requested = self.requested_extras.get(&name).cloned().unwrap_or_default();
+            for raw in meta.requires_dist() {
+                let Some(req) = resolver::parse_requirement(&raw) else { continue };
+                if !resolver::marker_applies(&req.marker, &self.python_version, &requested) {
+                    continue;
+                }
+                if !req.extras.is_empty() {
+                    self.requested_extras.entry(req.name.clone()).or_default().extend(req.extras.clone());
+                }
+                queue.push((req.name, req.constraint, level + 1));
+            }
+        }
+
+        let mut resolved = Vec::new();
+        for (name, version) in &self.assignments {
+            let file = self.selected_files.get(name);
+            resolved.push(Dependency {
+                name: name.clone(),
+                version: version.clone(),
+                source: file.map(|f| f.url.clone()).unwrap_or_default(),
+                dependencies: Vec::new(),
+                sha256: file.and_then(|f| f.sha256.clone()),
+            });
+        }
+        Ok(resolved)
+    }
+}
+
+/// Does `merged` pin `version` exactly via `==`? Used to let an explicit `==1.2.3` request
+/// still install a yanked release, mirroring pip's own yank handling.
+fn has_exact_pin(merged: &VersionSet, version: &Version) -> bool {
+    merged.reqs.iter().any(|r| r.op == "==" && &r.version == version)
+}
+
+/// Render a merged constraint set as a pip-style spec (e.g. `>=2.28,<3`) for
+/// [`crate::error::Error::VersionConflict`]'s diagnostic message.
+fn format_constraint(set: &VersionSet) -> String {
+    if set.reqs.is_empty() {
+        return "any version".to_string();
+    }
+    set.reqs
+        .iter()
+        .map(|r| format!("{}{}", r.op, format_version(&r.version)))
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+/// Render a parsed [`Version`] back to its PEP 440 text form, including the pre/post/dev/local
+/// segments `format_constraint` needs to surface - a constraint like `>=1.0.0rc1` failing for a
+/// prerelease-specific reason should say so rather than silently rounding down to `>=1.0.0`.
+fn format_version(v: &Version) -> String {
+    let mut s = if v.epoch != 0 {
+        format!("{}!", v.epoch)
+    } else {
+        String::new()
+    };
+    s.push_str(&v.release.iter().map(u64::to_string).collect::<Vec<_>>().join("."));
+    if let Some((tag, n)) = &v.pre {
+        s.push_str(tag);
+        s.push_str(&n.to_string());
+    }
+    if let Some(post) = v.post {
+        s.push_str(&format!(".post{}", post));
+    }
+    if let Some(dev) = v.dev {
+        s.push_str(&format!(".dev{}", dev));
+    }
+    if let Some(local) = &v.local {
+        s.push('+');
+        s.push_str(
+            &local
+                .iter()
+                .map(|seg| match seg {
+                    LocalSegment::Number(n) => n.to_string(),
+                    LocalSegment::Text(t) => t.clone(),
+                })
+                .collect::<Vec<_>>()
+                .join("."),
+        );
+    }
+    s
+}
+
+/// Resolve `package_name` (and the extras it was requested with) plus its full transitive
+/// closure, parsing `requires_dist` (PEP 508: name, extras, version specifier, marker) and
+/// pruning platform-specific deps via `resolver::marker_applies`. Cycles resolve to a no-op once
+/// the cycle's package is already assigned a satisfying version. Unlike a naive "last pin wins"
+/// resolver, every requirer's constraint on a package is accumulated and re-checked together, so
+/// a conflicting second requirement backjumps and re-picks rather than silently overwriting the
+/// first requirer's pin.
+pub async fn resolve_dependencies(
+    package_name: &str,
+    extras: &[String],
+    root_constraint: &VersionSet,
+    python_version: &str,
+    trusted_hosts: &[String],
+    require_hashes: bool,
+    client: &Arc<reqwest::Client>,
+) -> Result<Vec<Dependency>> {
+    let mut solver = Solver::new(client, python_version, trusted_hosts.to_vec(), require_hashes);
+    solver.resolve(package_name, extras, root_constraint.clone()).await
+}
+
+/// The result of checking a resolved requirement set against what's actually installed.
+pub struct InstallPlan {
+    /// Installed at exactly the resolved version already: nothing to do.
+    pub already_satisfied: Vec<Dependency>,
+    /// Installed, but at the wrong version (or `--force`): needs a fresh install over it.
+    pub reinstall: Vec<Dependency>,
+    /// Not installed at all.
+    pub install: Vec<Dependency>,
+}
+
+/// Partition a resolved requirement set against `installed` (from `site_packages::scan`) so
+/// repeated installs only touch what's actually missing or wrong, instead of redownloading and
+/// overwriting everything on every run.
+pub fn plan_install(resolved: Vec<Dependency>, installed: &HashMap<String, InstalledPackage>, force: bool) -> InstallPlan {
+    let mut plan = InstallPlan {
+        already_satisfied: Vec::new(),
+        reinstall: Vec::new(),
+        install: Vec::new(),
+    };
+
+    for dep in resolved {
+        match installed.get(&site_packages::normalize(&dep.name)) {
+            None => plan.install.push(dep),
+            Some(pkg) => {
+                let same_version = match (Version::parse(&pkg.version), Version::parse(&dep.version)) {
+                    (Some(a), Some(b)) => a == b,
+                    _ => pkg.version == dep.version,
+                };
+                if force || !same_version {
+                    plan.reinstall.push(dep);
+                } else {
+                    plan.already_satisfied.push(dep);
+                }
+            }
+        }
+    }
+
+    plan
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn dep(name: &str, version: &str) -> Dependency {
+        Dependency {
+            name: name.to_string(),
+            version: version.to_string(),
+            source: format!("https://example/{}-{}.whl", name, version),
+            dependencies: Vec::new(),
+            sha256: None,
+        }
+    }
+
+    fn installed(name: &str, version: &str) -> (String, InstalledPackage) {
+        (
+            site_packages::normalize(name),
+            InstalledPackage {
+                name: name.to_string(),
+                version: version.to_string(),
+                fields: HashMap::new(),
+            },
+        )
+    }
+
+    #[test]
+    fn plan_install_partitions_by_version() {
+        let resolved = vec![dep("foo", "1.0"), dep("bar", "2.0"), dep("baz", "3.0")];
+        let mut installed_map = HashMap::new();
+        installed_map.extend([installed("foo", "1.0"), installed("bar", "1.5")]);
+
+        let plan = plan_install(resolved, &installed_map, false);
+        assert_eq!(plan.already_satisfied.len(), 1);
+        assert_eq!(plan.already_satisfied[0].name, "foo");
+        assert_eq!(plan.reinstall.len(), 1);
+        assert_eq!(plan.reinstall[0].name, "bar");
+        assert_eq!(plan.install.len(), 1);
+        assert_eq!(plan.install[0].name, "baz");
+    }
+
+    #[test]
+    fn plan_install_force_reinstalls_everything() {
+        let resolved = vec![dep("foo", "1.0")];
+        let installed_map = HashMap::from([installed("foo", "1.0")]);
+        let plan = plan_install(resolved, &installed_map, true);
+        assert!(plan.already_satisfied.is_empty());
+        assert_eq!(plan.reinstall.len(), 1);
+    }
+
+    /// A conflicting second requirement must backjump and merge, not silently overwrite the
+    /// first requirer's pin: once `foo` is decided from clause `<2`, adding a clause `>=2` has
+    /// to make the merged constraint for `foo` unsatisfiable by the existing pin - and indeed by
+    /// any version at all, since `<2` and `>=2` admit no overlap.
+    #[test]
+    fn merged_constraint_combines_every_requirer_clause() {
+        let client = Arc::new(reqwest::Client::new());
+        let mut solver = Solver::new(&client, "3.11", vec!["pypi.org".to_string()], false);
+
+        solver.clauses.entry("foo".to_string()).or_default().push(Clause {
+            constraint: VersionSet::parse("<2"),
+        });
+        let v15 = Version::parse("1.5").unwrap();
+        assert!(solver.merged_constraint("foo").satisfies(&v15));
+
+        solver.assignments.insert("foo".to_string(), "1.5".to_string());
+        solver.decisions.push(Decision { package: "foo".to_string(), level: 1 });
+
+        solver.clauses.entry("foo".to_string()).or_default().push(Clause {
+            constraint: VersionSet::parse(">=2"),
+        });
+        let merged = solver.merged_constraint("foo");
+        // The existing 1.5 pin no longer satisfies the combined constraint from both requirers,
+        // and no candidate does either: `<2 AND >=2` is unsatisfiable by construction.
+        assert!(!merged.satisfies(&v15));
+        assert!(!merged.satisfies(&Version::parse("2.0").unwrap()));
+        assert!(!merged.satisfies(&Version::parse("1.999").unwrap()));
+    }
+
+    #[test]
+    fn backjump_to_undoes_decisions_after_level() {
+        let client = Arc::new(reqwest::Client::new());
+        let mut solver = Solver::new(&client, "3.11", vec![], false);
+
+        solver.assignments.insert("a".to_string(), "1.0".to_string());
+        solver.decisions.push(Decision { package: "a".to_string(), level: 1 });
+        solver.assignments.insert("b".to_string(), "1.0".to_string());
+        solver.decisions.push(Decision { package: "b".to_string(), level: 2 });
+
+        solver.backjump_to(0);
+
+        assert!(solver.assignments.is_empty());
+        assert!(solver.decisions.is_empty());
+    }
+}
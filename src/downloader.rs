@@ -2,35 +2,73 @@ use anyhow::Result;
 use futures_util::StreamExt;
 use indicatif::{ProgressBar, ProgressStyle};
 use reqwest::Client;
-use std::fs::File;
+use sha2::{Digest, Sha256};
+use std::fs::OpenOptions;
 use std::io::Write;
+use std::time::Instant;
+use crate::utils;
 
-pub async fn download_package(client: &Client, url: &str, dest: &str) -> Result<()> {
-    // Make request
+/// Download a single package async with streaming & progress. When `expected_sha256` is set,
+/// the received bytes are hashed as they stream in and the file is deleted (rather than cached)
+/// on a mismatch, so a corrupted or tampered download never reaches the installer.
+pub async fn download_package(client: &Client, url: &str, dest: &str, expected_sha256: Option<&str>) -> Result<()> {
+    let start_time = Instant::now();
     let resp = client.get(url).send().await?;
-    let total_size = resp
-        .content_length()
-        .ok_or_else(|| anyhow::anyhow!("Failed to get content length"))?;
+    let total_size = resp.content_length().unwrap_or(0);
 
-    // Progress bar
-    let pb = ProgressBar::new(total_size);
+    // Enhanced progress bar with speed and ETA
     let style = ProgressStyle::default_bar()
-        .template("{spinner:.green} [{bar:40.cyan/blue}] {bytes}/{total_bytes} ({eta})")?
+        .template("{spinner:.green} [{bar:40.cyan/blue}] {bytes}/{total_bytes} ({percent}%) [{elapsed_precise}] {binary_bytes_per_sec} ETA: {eta}")?
         .progress_chars("#>-");
+
+    let pb = ProgressBar::new(total_size);
     pb.set_style(style);
 
-    // Stream bytes
-    let mut stream = resp.bytes_stream();
+    let mut file = OpenOptions::new()
+        .create(true)
+        .write(true)
+        .truncate(true)
+        .open(dest)?;
 
-    // Open file
-    let mut file = File::create(dest)?;
+    let mut stream = resp.bytes_stream();
+    let mut downloaded = 0u64;
+    let mut hasher = Sha256::new();
 
     while let Some(item) = stream.next().await {
         let chunk = item?;
-        file.write_all(&chunk)?; // Use &chunk to fix `[u8]` size error
+        file.write_all(&chunk)?;
+        hasher.update(&chunk);
+        downloaded += chunk.len() as u64;
         pb.inc(chunk.len() as u64);
     }
 
-    pb.finish_with_message(format!("Downloaded {}", dest));
+    if let Some(expected) = expected_sha256 {
+        let actual = hex_encode(&hasher.finalize());
+        if !actual.eq_ignore_ascii_case(expected) {
+            pb.abandon_with_message(format!("❌ Hash mismatch for {}", dest));
+            std::fs::remove_file(dest).ok();
+            anyhow::bail!("hash mismatch for {}: expected {}, got {}", dest, expected, actual);
+        }
+    }
+
+    let elapsed = start_time.elapsed();
+    let speed = if elapsed.as_secs() > 0 {
+        downloaded as f64 / elapsed.as_secs() as f64
+    } else {
+        0.0
+    };
+
+    pb.finish_with_message(format!(
+        "✅ Downloaded {} ({}) in {:.2}s at {}/s",
+        dest,
+        utils::format_bytes(downloaded),
+        elapsed.as_secs_f64(),
+        utils::format_bytes(speed as u64)
+    ));
+
     Ok(())
 }
+
+fn hex_encode(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
@@ -0,0 +1,45 @@
+use miette::Diagnostic;
+use thiserror::Error;
+
+/// Crate-wide error type. Every variant carries an error code and actionable help text so a
+/// failure surfaces as a clean diagnostic instead of a bare `anyhow` chain or a panic.
+#[derive(Debug, Error, Diagnostic)]
+pub enum Error {
+    #[error("package '{name}' was not found on PyPI")]
+    #[diagnostic(code(mint::package_not_found), help("check the package name for typos, or search with `mint search {name}`"))]
+    PackageNotFound { name: String },
+
+    #[error("no version of '{package}' satisfies the requested constraint")]
+    #[diagnostic(code(mint::version_conflict), help("wanted {wanted}, but the newest compatible release found was {found}"))]
+    VersionConflict {
+        package: String,
+        wanted: String,
+        found: String,
+    },
+
+    #[error("failed to download {url}")]
+    #[diagnostic(code(mint::download_failed), help("check your network connection and that the URL is reachable"))]
+    DownloadFailed {
+        url: String,
+        #[source]
+        source: anyhow::Error,
+    },
+
+    #[error("hash mismatch: expected {expected}, got {actual}")]
+    #[diagnostic(code(mint::hash_mismatch), help("the downloaded file may be corrupt or tampered with; try clearing the cache and retrying"))]
+    HashMismatch { expected: String, actual: String },
+
+    #[error("failed to install wheel {path}")]
+    #[diagnostic(code(mint::wheel_install_failed), help("run with RUST_LOG=debug for the underlying extraction error"))]
+    WheelInstallFailed {
+        path: String,
+        #[source]
+        source: anyhow::Error,
+    },
+
+    #[error(transparent)]
+    #[diagnostic(code(mint::other))]
+    Other(#[from] anyhow::Error),
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
@@ -1,54 +1,68 @@
 use anyhow::Result;
+use std::fs;
 use std::process::Command;
+use tracing::info;
 
-/// Install a wheel file into the current venv
-pub fn install_wheel(path: &str) -> Result<()> {
-    let status = Command::new("python3")
-        .args(&["-m", "pip", "install", "--no-deps", path])
-        .status()?;
+use crate::error::Error;
+use crate::wheel;
 
-    if !status.success() {
-        anyhow::bail!("Failed to install wheel {}", path);
-    }
-    println!("✅ Installed {}", path);
-    Ok(())
+/// Install a wheel file into a venv (or the system interpreter) by unpacking it directly; no
+/// `pip` subprocess. Wraps the underlying extraction error in a typed `Error` so a bad wheel
+/// surfaces as a clean diagnostic instead of a bare `anyhow` chain.
+pub fn install_wheel(path: &str, venv_path: Option<&str>) -> crate::error::Result<()> {
+    wheel::install_wheel(path, venv_path).map_err(|source| Error::WheelInstallFailed {
+        path: path.to_string(),
+        source,
+    })
 }
 
-/// Uninstall a package from the current venv
-pub fn uninstall_package(pkg: &str) -> Result<()> {
-    let status = Command::new("python3")
-        .args(&["-m", "pip", "uninstall", "-y", pkg])
-        .status()?;
-
-    if !status.success() {
-        anyhow::bail!("Failed to uninstall {}", pkg);
+/// Uninstall a package by removing exactly the files its RECORD lists.
+pub fn uninstall_package(pkg: &str, venv_path: Option<&str>) -> Result<()> {
+    let files = wheel::installed_files(pkg, venv_path)?;
+    for file in &files {
+        if file.exists() {
+            fs::remove_file(file)?;
+        }
     }
+    info!("✅ Uninstalled {} ({} files removed)", pkg, files.len());
     println!("✅ Uninstalled {}", pkg);
     Ok(())
 }
 
-/// Create virtual environment
-pub fn create_venv(name: &str) -> Result<()> {
-    let status = Command::new("python3")
-        .args(&["-m", "venv", name])
-        .status()?;
+/// Create a venv with `python_path` (resolved by `python::find_best_match`/`bootstrap_standalone`),
+/// falling back to the OS-default `python3`/`python.exe` when the caller didn't request a specific
+/// interpreter.
+pub fn create_venv(name: &str, python_path: Option<&str>) -> Result<()> {
+    let python_cmd = python_path.map(str::to_string).unwrap_or_else(|| {
+        if cfg!(target_os = "windows") {
+            "python.exe".to_string()
+        } else {
+            "python3".to_string()
+        }
+    });
 
+    let status = Command::new(&python_cmd)
+        .args(["-m", "venv", name])
+        .status()?;
     if !status.success() {
-        anyhow::bail!("Failed to create venv {}", name);
+        anyhow::bail!("Failed to create venv {} using {}", name, python_cmd);
     }
     println!("✅ Created venv {}", name);
     Ok(())
 }
 
-/// Run script in venv
 pub fn run_in_venv(venv: &str, script: &str) -> Result<()> {
-    let python_path = format!("{}/bin/python3", venv);
-    let status = Command::new(python_path)
-        .args(&["-c", script])
-        .status()?;
+    let python = if cfg!(target_os = "windows") {
+        format!("{}\\Scripts\\python.exe", venv)
+    } else {
+        format!("{}/bin/python3", venv)
+    };
 
+    let status = Command::new(&python)
+        .args(["-c", script])
+        .status()?;
     if !status.success() {
-        anyhow::bail!("Failed to run script in venv {}", venv);
+        anyhow::bail!("Script failed in venv {} using {}", venv, python);
     }
     Ok(())
 }
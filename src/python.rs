@@ -0,0 +1,249 @@
+use anyhow::Result;
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::sync::Arc;
+use tracing::info;
+use sha2::{Digest, Sha256};
+
+use crate::downloader;
+
+/// Base URL for python-build-standalone releases; see
+/// <https://github.com/indygreg/python-build-standalone>.
+const STANDALONE_BASE_URL: &str = "https://github.com/indygreg/python-build-standalone/releases/download";
+/// Pinned release tag. python-build-standalone cuts a new one per CPython patch release, and
+/// pinning here keeps `mint venv-create --python` reproducible instead of silently picking up
+/// whatever the latest toolchain build happens to be.
+const STANDALONE_RELEASE_TAG: &str = "20240107";
+
+/// One interpreter mint found on `PATH` (or built via [`bootstrap_standalone`]): enough of
+/// `sys.version_info` to match against a `--python` request, plus the executable's path.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct InterpreterKey {
+    pub major: u32,
+    pub minor: u32,
+    pub patch: u32,
+    pub path: PathBuf,
+}
+
+/// Scan every directory on `PATH` for `python3`/`python3.x` executables and query each one's
+/// real version via `sys.version_info`, so a request like `3.11` can match even though the
+/// executable on disk might be named just `python3.11` or `python3`.
+pub fn discover_interpreters() -> Vec<InterpreterKey> {
+    let Some(path_var) = std::env::var_os("PATH") else {
+        return Vec::new();
+    };
+
+    let mut found: HashMap<PathBuf, InterpreterKey> = HashMap::new();
+    for dir in std::env::split_paths(&path_var) {
+        let Ok(entries) = fs::read_dir(&dir) else { continue };
+        for entry in entries.flatten() {
+            let name = entry.file_name();
+            let name = name.to_string_lossy();
+            if !is_python_executable_name(&name) {
+                continue;
+            }
+            let path = entry.path();
+            if found.contains_key(&path) {
+                continue;
+            }
+            if let Some(key) = query_version(&path) {
+                found.insert(path, key);
+            }
+        }
+    }
+
+    let mut keys: Vec<InterpreterKey> = found.into_values().collect();
+    keys.sort_by(|a, b| b.cmp(a)); // newest first
+    keys
+}
+
+fn is_python_executable_name(name: &str) -> bool {
+    if cfg!(target_os = "windows") {
+        return name.eq_ignore_ascii_case("python.exe");
+    }
+    if name == "python3" {
+        return true;
+    }
+    name.strip_prefix("python3.")
+        .map(|rest| !rest.is_empty() && rest.chars().all(|c| c.is_ascii_digit()))
+        .unwrap_or(false)
+}
+
+/// Run `path -c "import sys; print(sys.version_info)"` and parse the
+/// `sys.version_info(major=3, minor=11, micro=2, releaselevel='final', serial=0)` repr it prints.
+fn query_version(path: &Path) -> Option<InterpreterKey> {
+    let output = Command::new(path)
+        .args(["-c", "import sys; print(sys.version_info)"])
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    let text = String::from_utf8_lossy(&output.stdout);
+    let major = extract_field(&text, "major=")?;
+    let minor = extract_field(&text, "minor=")?;
+    let patch = extract_field(&text, "micro=")?;
+    Some(InterpreterKey { major, minor, patch, path: path.to_path_buf() })
+}
+
+fn extract_field(text: &str, key: &str) -> Option<u32> {
+    let idx = text.find(key)? + key.len();
+    let digits: String = text[idx..].chars().take_while(|c| c.is_ascii_digit()).collect();
+    digits.parse().ok()
+}
+
+/// Resolve a `--python` request against [`discover_interpreters`]: either a full path to an
+/// executable, or a version like `3.11` or `3` (matched against the highest installed patch).
+pub fn find_best_match(request: &str) -> Option<InterpreterKey> {
+    let as_path = PathBuf::from(request);
+    if as_path.is_file() {
+        return query_version(&as_path);
+    }
+
+    let mut parts = request.split('.');
+    let wanted_major: u32 = parts.next()?.parse().ok()?;
+    let wanted_minor: Option<u32> = parts.next().and_then(|m| m.parse().ok());
+
+    discover_interpreters()
+        .into_iter()
+        .find(|key| key.major == wanted_major && wanted_minor.is_none_or(|m| key.minor == m))
+}
+
+/// Resolve a version request to the concrete `major.minor.patch` this pinned
+/// python-build-standalone release actually ships. A full 3-part request (`3.12.1`) is used
+/// as-is; a 2-part request (`3.12`, the form `mint venv-create --python` advertises working
+/// even when nothing local matches) is resolved to the newest patch this release tag published
+/// for the host platform, by scanning the release's own `SHA256SUMS` listing.
+async fn resolve_patch_version(version: &str, client: &Arc<reqwest::Client>) -> Result<String> {
+    let parts: Vec<&str> = version.split('.').collect();
+    match parts.as_slice() {
+        [_, _, _] => Ok(version.to_string()),
+        [major, minor] => {
+            let platform_tag = host_platform_tag()?;
+            let checksums_url = format!("{}/{}/SHA256SUMS", STANDALONE_BASE_URL, STANDALONE_RELEASE_TAG);
+            let checksums = client.get(&checksums_url).send().await?.text().await?;
+
+            let prefix = format!("cpython-{}.{}.", major, minor);
+            let suffix = format!("+{}-{}-install_only.tar.gz", STANDALONE_RELEASE_TAG, platform_tag);
+
+            let newest_patch = checksums
+                .lines()
+                .filter_map(|line| line.split_once("  ").map(|(_, name)| name.trim()))
+                .filter_map(|name| name.strip_prefix(&prefix)?.strip_suffix(&suffix))
+                .filter_map(|patch| patch.parse::<u64>().ok())
+                .max();
+
+            newest_patch.map(|patch| format!("{}.{}.{}", major, minor, patch)).ok_or_else(|| {
+                anyhow::anyhow!(
+                    "no python-build-standalone release of CPython {}.{} found for this platform in release {}",
+                    major,
+                    minor,
+                    STANDALONE_RELEASE_TAG
+                )
+            })
+        }
+        _ => anyhow::bail!(
+            "'{}' isn't a version mint can bootstrap; use a major.minor or major.minor.patch form, e.g. 3.12 or 3.12.1",
+            version
+        ),
+    }
+}
+
+/// Download, checksum-verify, and extract a python-build-standalone CPython build matching
+/// `version` (`major.minor` or `major.minor.patch`, e.g. `3.12` or `3.12.1`) into
+/// `~/.mint/pythons/<version>/`, returning the path to its `python3` executable. Used as a
+/// fallback when no local interpreter satisfies a `--python` request, so
+/// `mint venv-create env --python 3.12` works even on a fresh machine with nothing installed.
+pub async fn bootstrap_standalone(version: &str, client: &Arc<reqwest::Client>) -> Result<PathBuf> {
+    let version = resolve_patch_version(version, client).await?;
+
+    let install_dir = pythons_dir()?.join(&version);
+    let python_bin = install_dir.join("python").join("bin").join("python3");
+    if python_bin.exists() {
+        info!("Using previously bootstrapped CPython {} at {:?}", version, python_bin);
+        return Ok(python_bin);
+    }
+
+    let platform_tag = host_platform_tag()?;
+    let archive_name = format!("cpython-{}+{}-{}-install_only.tar.gz", version, STANDALONE_RELEASE_TAG, platform_tag);
+    let url = format!("{}/{}/{}", STANDALONE_BASE_URL, STANDALONE_RELEASE_TAG, archive_name);
+    let checksums_url = format!("{}/{}/SHA256SUMS", STANDALONE_BASE_URL, STANDALONE_RELEASE_TAG);
+
+    fs::create_dir_all(&install_dir)?;
+    let archive_path = install_dir.join(&archive_name);
+
+    info!("Downloading CPython {} standalone build from {}", version, url);
+    downloader::download_package(client, &url, archive_path.to_str().unwrap_or(""), None).await?;
+
+    let checksums = client.get(&checksums_url).send().await?.text().await?;
+    let expected = checksums
+        .lines()
+        .find_map(|line| {
+            let (hash, name) = line.split_once("  ")?;
+            (name.trim() == archive_name).then(|| hash.trim().to_string())
+        })
+        .ok_or_else(|| anyhow::anyhow!("no checksum for {} in {}", archive_name, checksums_url))?;
+
+    let actual = sha256_hex(&archive_path)?;
+    if !actual.eq_ignore_ascii_case(&expected) {
+        fs::remove_file(&archive_path).ok();
+        anyhow::bail!("checksum mismatch for {}: expected {}, got {}", archive_name, expected, actual);
+    }
+
+    extract_tar_gz(&archive_path, &install_dir)?;
+    fs::remove_file(&archive_path).ok();
+
+    if !python_bin.exists() {
+        anyhow::bail!("extracted {} but {:?} is missing", archive_name, python_bin);
+    }
+    info!("✅ Bootstrapped CPython {} at {:?}", version, python_bin);
+    Ok(python_bin)
+}
+
+fn pythons_dir() -> Result<PathBuf> {
+    let mut path = dirs::home_dir().unwrap_or_else(|| PathBuf::from("."));
+    path.push(".mint");
+    path.push("pythons");
+    Ok(path)
+}
+
+fn host_platform_tag() -> Result<&'static str> {
+    match (std::env::consts::OS, std::env::consts::ARCH) {
+        ("linux", "x86_64") => Ok("x86_64-unknown-linux-gnu"),
+        ("linux", "aarch64") => Ok("aarch64-unknown-linux-gnu"),
+        ("macos", "x86_64") => Ok("x86_64-apple-darwin"),
+        ("macos", "aarch64") => Ok("aarch64-apple-darwin"),
+        ("windows", "x86_64") => Ok("x86_64-pc-windows-msvc"),
+        (os, arch) => anyhow::bail!("no python-build-standalone release for {}/{}", os, arch),
+    }
+}
+
+fn sha256_hex(path: &Path) -> Result<String> {
+    let bytes = fs::read(path)?;
+    let mut hasher = Sha256::new();
+    hasher.update(&bytes);
+    Ok(hasher.finalize().iter().map(|b| format!("{:02x}", b)).collect())
+}
+
+fn extract_tar_gz(archive_path: &Path, dest: &Path) -> Result<()> {
+    let file = fs::File::open(archive_path)?;
+    let decoder = flate2::read::GzDecoder::new(file);
+    let mut archive = tar::Archive::new(decoder);
+    archive.unpack(dest)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn is_python_executable_name_matches_versioned_binaries() {
+        assert!(is_python_executable_name("python3"));
+        assert!(is_python_executable_name("python3.11"));
+        assert!(!is_python_executable_name("python3.11-config"));
+        assert!(!is_python_executable_name("python"));
+    }
+}
@@ -21,8 +21,8 @@ pub fn parse_requirements(path: &PathBuf) -> Result<Vec<String>> {
         }
 
         // Handle -r includes
-        if line.starts_with("-r ") {
-            let include_path = line[3..].trim();
+        if let Some(include_path) = line.strip_prefix("-r ") {
+            let include_path = include_path.trim();
             let include_file = path.parent()
                 .map(|p| p.join(include_path))
                 .unwrap_or_else(|| PathBuf::from(include_path));
@@ -45,33 +45,20 @@ pub fn parse_requirements(path: &PathBuf) -> Result<Vec<String>> {
     Ok(packages)
 }
 
-/// Generate requirements.txt from installed packages
+/// Generate a `requirements.txt`-style listing (`name==version` per line, sorted) straight from
+/// the installed dist-info metadata, the same source `List`/`Show` read from. No `pip` subprocess.
 pub fn generate_requirements(venv_path: Option<&str>) -> Result<String> {
-    let python = if let Some(v) = venv_path {
-        if cfg!(target_os = "windows") {
-            format!("{}/Scripts/python.exe", v)
-        } else {
-            format!("{}/bin/python3", v)
-        }
-    } else {
-        if cfg!(target_os = "windows") {
-            "python.exe".to_string()
-        } else {
-            "python3".to_string()
-        }
-    };
+    let installed = crate::site_packages::scan(venv_path)?;
+    let mut packages: Vec<_> = installed.values().collect();
+    packages.sort_by(|a, b| a.name.cmp(&b.name));
 
-    let output = std::process::Command::new(&python)
-        .args(&["-m", "pip", "freeze"])
-        .output()?;
-
-    if !output.status.success() {
-        anyhow::bail!("Failed to generate requirements");
-    }
+    let requirements: String = packages
+        .iter()
+        .map(|pkg| format!("{}=={}\n", pkg.name, pkg.version))
+        .collect();
 
-    let requirements = String::from_utf8_lossy(&output.stdout);
-    info!("Generated requirements for {} packages", requirements.lines().count());
-    Ok(requirements.to_string())
+    info!("Generated requirements for {} packages", packages.len());
+    Ok(requirements)
 }
 
 /// Save requirements to file
@@ -1,11 +1,13 @@
 use serde::Deserialize;
 use reqwest::Client;
-use anyhow::Result;
 use serde_json::Value;
+use std::sync::Arc;
 
-#[derive(Deserialize, Debug)]
+use crate::error::Error;
+use crate::version::{Version, VersionSet};
+
+#[derive(Deserialize, Debug, Clone)]
 pub struct PyPiResponse {
-    #[allow(dead_code)]
     pub info: Value,
     pub releases: Value,
 }
@@ -14,11 +16,264 @@ impl PyPiResponse {
     pub fn releases(&self) -> Option<&serde_json::Map<String, Value>> {
         self.releases.as_object()
     }
+
+    /// `requires_dist` entries from PyPI's `info`, e.g. `["idna (>=2.5,<4) ; python_version >= \"3.6\""]`.
+    pub fn requires_dist(&self) -> Vec<String> {
+        self.info
+            .get("requires_dist")
+            .and_then(|v| v.as_array())
+            .map(|arr| arr.iter().filter_map(|v| v.as_str().map(str::to_string)).collect())
+            .unwrap_or_default()
+    }
+
+    /// Pick the first file for `version` whose host is in `trusted_hosts`, carrying along its
+    /// expected `sha256` digest (if PyPI published one) and `yanked` flag.
+    pub fn select_file(&self, version: &str, trusted_hosts: &[String]) -> Option<ReleaseFile> {
+        let files = self.releases()?.get(version)?.as_array()?;
+        files.iter().find_map(|file| {
+            let url = file.get("url")?.as_str()?.to_string();
+            let host = url.split("://").nth(1)?.split('/').next()?;
+            if !trusted_hosts.iter().any(|h| h == host) {
+                return None;
+            }
+            let sha256 = file.get("digests").and_then(|d| d.get("sha256")).and_then(|s| s.as_str()).map(str::to_string);
+            let yanked = file.get("yanked").and_then(|y| y.as_bool()).unwrap_or(false);
+            Some(ReleaseFile { url, sha256, yanked })
+        })
+    }
+}
+
+/// One PyPI file entry for a resolved version: its URL, expected digest (if published), and
+/// whether the maintainer has yanked it since.
+#[derive(Debug, Clone)]
+pub struct ReleaseFile {
+    pub url: String,
+    pub sha256: Option<String>,
+    pub yanked: bool,
 }
 
-pub async fn fetch_package_metadata(client: &Client, package: &str) -> Result<PyPiResponse> {
+/// Fetch metadata from PyPI (async). Returns a typed `Error` (rather than a bare network
+/// error) so a bad package name surfaces as a clean diagnostic instead of an opaque chain.
+pub async fn fetch_package_metadata(client: &Arc<Client>, package: &str) -> crate::error::Result<PyPiResponse> {
     let url = format!("https://pypi.org/pypi/{}/json", package);
-    let resp: PyPiResponse = client.get(&url).send().await?.json().await?;
+    let resp = client.get(&url).send().await.map_err(|e| Error::DownloadFailed {
+        url: url.clone(),
+        source: e.into(),
+    })?;
+
+    if resp.status() == reqwest::StatusCode::NOT_FOUND {
+        return Err(Error::PackageNotFound {
+            name: package.to_string(),
+        });
+    }
+
+    let resp: PyPiResponse = resp.json().await.map_err(|e| Error::DownloadFailed {
+        url,
+        source: e.into(),
+    })?;
     println!("✅ Fetched metadata for {}", package);
     Ok(resp)
 }
+
+/// A PEP 508 requirement: name, extras requested, version constraint, and marker.
+#[derive(Debug, Clone)]
+pub struct Requirement {
+    pub name: String,
+    pub extras: Vec<String>,
+    pub constraint: VersionSet,
+    pub marker: Option<String>,
+}
+
+/// Parse a `requires_dist` entry like `requests[security] (>=2.28,<3) ; python_version >= "3.6"`.
+pub fn parse_requirement(raw: &str) -> Option<Requirement> {
+    let (spec_part, marker) = match raw.split_once(';') {
+        Some((s, m)) => (s.trim(), Some(m.trim().to_string())),
+        None => (raw.trim(), None),
+    };
+
+    let (name_and_extras, constraint_part) = match spec_part.find(['(', '>', '<', '=', '!', '~']) {
+        Some(idx) => (spec_part[..idx].trim(), spec_part[idx..].trim()),
+        None => (spec_part, ""),
+    };
+
+    let (name, extras) = match name_and_extras.split_once('[') {
+        Some((n, rest)) => {
+            let extras_str = rest.trim_end_matches(']');
+            let extras = extras_str.split(',').map(|e| e.trim().to_string()).filter(|e| !e.is_empty()).collect();
+            (n.trim().to_string(), extras)
+        }
+        None => (name_and_extras.trim().to_string(), Vec::new()),
+    };
+    if name.is_empty() {
+        return None;
+    }
+
+    let constraint_part = constraint_part.trim_start_matches('(').trim_end_matches(')');
+    Some(Requirement {
+        name,
+        extras,
+        constraint: VersionSet::parse(constraint_part),
+        marker,
+    })
+}
+
+/// Evaluate `python_version`, `sys_platform`, and `extra` markers against the target
+/// interpreter/platform and the set of extras the user actually requested. Supports compound
+/// `and`/`or` marker expressions (with parentheses), not just a single clause.
+pub fn marker_applies(marker: &Option<String>, python_version: &str, requested_extras: &[String]) -> bool {
+    let marker = match marker {
+        Some(m) => m,
+        None => return true,
+    };
+    evaluate_marker_expr(marker, python_version, requested_extras)
+}
+
+/// Strip one layer of outer parens, if `s` is wrapped end-to-end in a single matching pair.
+fn strip_outer_parens(s: &str) -> &str {
+    let t = s.trim();
+    if !t.starts_with('(') || !t.ends_with(')') {
+        return t;
+    }
+    let mut depth = 0;
+    for (i, c) in t.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => {
+                depth -= 1;
+                if depth == 0 && i != t.len() - 1 {
+                    // The opening paren closes before the end: not a single wrapping pair.
+                    return t;
+                }
+            }
+            _ => {}
+        }
+    }
+    t[1..t.len() - 1].trim()
+}
+
+/// Split `s` on every top-level occurrence of `sep` (i.e. not nested inside parens). Walks
+/// `char_indices` (rather than raw bytes) so a multi-byte character in the marker string never
+/// lands `s[i..]` on a non-char-boundary byte and panics.
+fn split_top_level<'a>(s: &'a str, sep: &str) -> Option<Vec<&'a str>> {
+    let mut depth = 0i32;
+    let mut start = 0;
+    let mut parts = Vec::new();
+    let mut skip_until = 0;
+    for (i, c) in s.char_indices() {
+        if i < skip_until {
+            continue;
+        }
+        match c {
+            '(' => depth += 1,
+            ')' => depth -= 1,
+            _ => {}
+        }
+        if depth == 0 && s[i..].starts_with(sep) {
+            parts.push(&s[start..i]);
+            skip_until = i + sep.len();
+            start = skip_until;
+        }
+    }
+    if parts.is_empty() {
+        None
+    } else {
+        parts.push(&s[start..]);
+        Some(parts)
+    }
+}
+
+fn evaluate_marker_expr(expr: &str, python_version: &str, requested_extras: &[String]) -> bool {
+    let expr = strip_outer_parens(expr);
+    // `or` binds loosest, so split on it first; each side may still contain `and`.
+    if let Some(parts) = split_top_level(expr, " or ") {
+        return parts.iter().any(|p| evaluate_marker_expr(p, python_version, requested_extras));
+    }
+    if let Some(parts) = split_top_level(expr, " and ") {
+        return parts.iter().all(|p| evaluate_marker_expr(p, python_version, requested_extras));
+    }
+    evaluate_marker_clause(expr.trim(), python_version, requested_extras)
+}
+
+/// Evaluate a single atomic marker clause (no `and`/`or`), e.g. `python_version >= "3.6"`.
+fn evaluate_marker_clause(marker: &str, python_version: &str, requested_extras: &[String]) -> bool {
+    if marker.contains("extra") {
+        // `extra == "security"` style markers: only true if the user asked for that extra.
+        if let Some(idx) = marker.find("==") {
+            let rhs = marker[idx + 2..].trim().trim_matches('"').trim_matches('\'');
+            return requested_extras.iter().any(|e| e == rhs);
+        }
+        return false;
+    }
+
+    if marker.contains("sys_platform") {
+        let current = if cfg!(target_os = "windows") {
+            "win32"
+        } else if cfg!(target_os = "macos") {
+            "darwin"
+        } else {
+            "linux"
+        };
+        if let Some(idx) = marker.find("==") {
+            let rhs = marker[idx + 2..].trim().trim_matches('"').trim_matches('\'');
+            return current == rhs;
+        }
+        if let Some(idx) = marker.find("!=") {
+            let rhs = marker[idx + 2..].trim().trim_matches('"').trim_matches('\'');
+            return current != rhs;
+        }
+        return true;
+    }
+
+    if marker.contains("python_version") {
+        for op in &[">=", "<=", "==", "!=", ">", "<"] {
+            if let Some(idx) = marker.find(op) {
+                let rhs = marker[idx + op.len()..].trim().trim_matches('"').trim_matches('\'');
+                if let (Some(candidate), Some(target)) = (Version::parse(python_version), Version::parse(rhs)) {
+                    return VersionSet {
+                        reqs: vec![crate::version::VersionReq {
+                            op: op.to_string(),
+                            version: target,
+                        }],
+                    }
+                    .satisfies(&candidate);
+                }
+            }
+        }
+    }
+
+    // Markers we don't evaluate (implementation_name, platform_machine, ...) default to included.
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn compound_and_marker_requires_both_sides() {
+        let marker = Some(r#"python_version >= "3.6" and python_version < "4.0""#.to_string());
+        assert!(marker_applies(&marker, "3.8", &[]));
+        assert!(!marker_applies(&marker, "3.5", &[]));
+        assert!(!marker_applies(&marker, "4.0", &[]));
+    }
+
+    #[test]
+    fn compound_or_marker_requires_either_side() {
+        let marker = Some(r#"sys_platform == "win32" or python_version < "3.7""#.to_string());
+        assert!(marker_applies(&marker, "3.6", &[]));
+    }
+
+    #[test]
+    fn parenthesized_compound_marker() {
+        let marker = Some(r#"(python_version >= "3.6" and python_version < "4.0") or extra == "security""#.to_string());
+        assert!(marker_applies(&marker, "3.8", &[]));
+        assert!(!marker_applies(&marker, "3.5", &[]));
+        assert!(marker_applies(&marker, "3.5", &["security".to_string()]));
+    }
+
+    #[test]
+    fn compound_marker_with_multibyte_text_does_not_panic() {
+        let marker = Some(r#"python_version >= "3.6" and sys_platform == "café""#.to_string());
+        assert!(!marker_applies(&marker, "3.8", &[]));
+    }
+}
@@ -0,0 +1,85 @@
+use anyhow::Result;
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+use crate::wheel;
+
+/// One installed distribution's parsed `METADATA`: `name`/`version` pulled out for convenience,
+/// plus every other field verbatim for `Show`.
+#[derive(Debug, Clone)]
+pub struct InstalledPackage {
+    pub name: String,
+    pub version: String,
+    pub fields: HashMap<String, String>,
+}
+
+/// PEP 503 normalization: case-insensitive, `-`/`_`/`.` runs are all equivalent.
+pub fn normalize(name: &str) -> String {
+    let mut out = String::with_capacity(name.len());
+    let mut last_was_sep = false;
+    for c in name.to_lowercase().chars() {
+        if c == '-' || c == '_' || c == '.' {
+            if !last_was_sep {
+                out.push('-');
+            }
+            last_was_sep = true;
+        } else {
+            out.push(c);
+            last_was_sep = false;
+        }
+    }
+    out
+}
+
+/// Scan every `*.dist-info/METADATA` under the venv's (or system interpreter's) site-packages,
+/// keyed by normalized package name.
+pub fn scan(venv_path: Option<&str>) -> Result<HashMap<String, InstalledPackage>> {
+    let purelib = wheel::site_packages_dir(venv_path)?;
+    let mut installed = HashMap::new();
+
+    if !purelib.exists() {
+        return Ok(installed);
+    }
+
+    for entry in fs::read_dir(&purelib)? {
+        let entry = entry?;
+        let dir_name = entry.file_name();
+        let dir_name = dir_name.to_string_lossy();
+        if !dir_name.ends_with(".dist-info") {
+            continue;
+        }
+
+        let metadata_path = entry.path().join("METADATA");
+        let Ok(pkg) = read_metadata(&metadata_path) else {
+            continue;
+        };
+        installed.insert(normalize(&pkg.name), pkg);
+    }
+
+    Ok(installed)
+}
+
+/// Parse the RFC822-style header block of a `METADATA` file into `field -> value`, stopping at
+/// the blank line that separates headers from the long description.
+fn read_metadata(path: &Path) -> Result<InstalledPackage> {
+    let content = fs::read_to_string(path)?;
+    let mut fields = HashMap::new();
+
+    for line in content.lines() {
+        if line.is_empty() {
+            break; // Start of the long description body.
+        }
+        if let Some((key, value)) = line.split_once(':') {
+            fields.insert(key.trim().to_string(), value.trim().to_string());
+        }
+    }
+
+    let name = fields.get("Name").cloned().unwrap_or_default();
+    let version = fields.get("Version").cloned().unwrap_or_default();
+    if name.is_empty() || version.is_empty() {
+        anyhow::bail!("METADATA at {:?} is missing Name or Version", path);
+    }
+
+    Ok(InstalledPackage { name, version, fields })
+}
@@ -0,0 +1,271 @@
+//! Minimal PEP 440 version parsing and comparison, enough to drive resolver backtracking.
+use std::cmp::Ordering;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Version {
+    pub epoch: u64,
+    pub release: Vec<u64>,
+    pub pre: Option<(String, u64)>,
+    pub post: Option<u64>,
+    pub dev: Option<u64>,
+    /// The `+local` segment (e.g. `+cu118`), normalized to lowercase with `-`/`_` mapped to `.`.
+    /// Ignored for satisfying version specifiers (PEP 440 local versions never appear in
+    /// `requires_dist`), but compared when ordering two otherwise-identical releases.
+    pub local: Option<Vec<LocalSegment>>,
+}
+
+/// One dot-separated component of a local version, compared numerically when both sides parse
+/// as integers and lexically otherwise, per PEP 440.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LocalSegment {
+    Number(u64),
+    Text(String),
+}
+
+impl Ord for LocalSegment {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (LocalSegment::Number(a), LocalSegment::Number(b)) => a.cmp(b),
+            (LocalSegment::Text(a), LocalSegment::Text(b)) => a.cmp(b),
+            // Numeric segments sort after alphanumeric ones at the same position.
+            (LocalSegment::Number(_), LocalSegment::Text(_)) => Ordering::Greater,
+            (LocalSegment::Text(_), LocalSegment::Number(_)) => Ordering::Less,
+        }
+    }
+}
+
+impl PartialOrd for LocalSegment {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Version {
+    pub fn parse(raw: &str) -> Option<Version> {
+        let raw = raw.trim();
+        let (epoch, rest) = match raw.split_once('!') {
+            Some((e, r)) => (e.parse().ok()?, r),
+            None => (0, raw),
+        };
+
+        let (rest, local) = match rest.split_once('+') {
+            Some((r, l)) => (r, Some(parse_local(l))),
+            None => (rest, None),
+        };
+
+        let mut release = Vec::new();
+        let mut chars = rest.char_indices().peekable();
+        let mut idx = 0;
+        while let Some(&(i, c)) = chars.peek() {
+            if c.is_ascii_digit() || c == '.' {
+                idx = i + c.len_utf8();
+                chars.next();
+            } else {
+                break;
+            }
+        }
+        let release_part = &rest[..idx];
+        for seg in release_part.split('.') {
+            if seg.is_empty() {
+                continue;
+            }
+            release.push(seg.parse().ok()?);
+        }
+        if release.is_empty() {
+            return None;
+        }
+
+        let suffix = &rest[idx..];
+        // `parse_suffix` tries the longest alias first (`alpha` before `a`, `beta` before `b`,
+        // ...) so `1.0alpha2` isn't misread as the 1-char `a` tag with the digits dropped, then
+        // we canonicalize aliases (`alpha`->`a`, `beta`->`b`, `c`->`rc`) so `1.0a1 < 1.0alpha2`
+        // compares as the same prerelease kind instead of by alias spelling.
+        let pre = parse_suffix(suffix, &["a", "alpha", "b", "beta", "rc", "c"])
+            .map(|(tag, n)| (canonical_pre_tag(&tag), n));
+        let post = parse_suffix(suffix, &["post", "rev", "r"]).map(|(_, n)| n);
+        let dev = parse_suffix(suffix, &["dev"]).map(|(_, n)| n);
+
+        Some(Version {
+            epoch,
+            release,
+            pre,
+            post,
+            dev,
+            local,
+        })
+    }
+}
+
+fn parse_local(local: &str) -> Vec<LocalSegment> {
+    local
+        .to_lowercase()
+        .split(['.', '-', '_'])
+        .filter(|s| !s.is_empty())
+        .map(|seg| match seg.parse::<u64>() {
+            Ok(n) => LocalSegment::Number(n),
+            Err(_) => LocalSegment::Text(seg.to_string()),
+        })
+        .collect()
+}
+
+/// Canonicalize a prerelease tag alias to the PEP 440 form used for comparison, so `alpha`/`a`,
+/// `beta`/`b`, and `c`/`rc` all order as the same prerelease kind regardless of which spelling a
+/// release used.
+fn canonical_pre_tag(tag: &str) -> String {
+    match tag {
+        "a" | "alpha" => "a",
+        "b" | "beta" => "b",
+        "rc" | "c" => "rc",
+        other => other,
+    }
+    .to_string()
+}
+
+/// Find the first (longest-alias-first) tag in `tags` that occurs in `s`, returning the tag and
+/// the digits immediately following it. Aliases are tried longest-first so e.g. `alpha` matches
+/// before the shorter `a` inside `1.0alpha2` (otherwise `a` would match at position 0 and the
+/// `lpha2` remainder, having no leading digit, would silently parse as release number 0).
+fn parse_suffix(s: &str, tags: &[&str]) -> Option<(String, u64)> {
+    let lower = s.to_lowercase();
+    let mut by_len_desc: Vec<&&str> = tags.iter().collect();
+    by_len_desc.sort_by_key(|t| std::cmp::Reverse(t.len()));
+    for tag in by_len_desc {
+        if let Some(pos) = lower.find(tag) {
+            let after = &lower[pos + tag.len()..];
+            let digits: String = after.chars().take_while(|c| c.is_ascii_digit()).collect();
+            let n = digits.parse().unwrap_or(0);
+            return Some((tag.to_string(), n));
+        }
+    }
+    None
+}
+
+impl Ord for Version {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.epoch
+            .cmp(&other.epoch)
+            .then_with(|| self.release.cmp(&other.release))
+            .then_with(|| {
+                // No pre-release sorts after any pre-release (1.0 > 1.0rc1).
+                match (&self.pre, &other.pre) {
+                    (None, None) => Ordering::Equal,
+                    (None, Some(_)) => Ordering::Greater,
+                    (Some(_), None) => Ordering::Less,
+                    (Some(a), Some(b)) => a.cmp(b),
+                }
+            })
+            .then_with(|| self.post.unwrap_or(0).cmp(&other.post.unwrap_or(0)))
+            .then_with(|| {
+                // A dev release sorts before the release it precedes.
+                match (&self.dev, &other.dev) {
+                    (None, None) => Ordering::Equal,
+                    (None, Some(_)) => Ordering::Greater,
+                    (Some(_), None) => Ordering::Less,
+                    (Some(a), Some(b)) => a.cmp(b),
+                }
+            })
+            .then_with(|| {
+                // No local version sorts before any local version (1.0 < 1.0+cpu).
+                match (&self.local, &other.local) {
+                    (None, None) => Ordering::Equal,
+                    (None, Some(_)) => Ordering::Less,
+                    (Some(_), None) => Ordering::Greater,
+                    (Some(a), Some(b)) => a.cmp(b),
+                }
+            })
+    }
+}
+
+impl PartialOrd for Version {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// A single PEP 440 comparison clause, e.g. `>=2.28`.
+#[derive(Debug, Clone)]
+pub struct VersionReq {
+    pub op: String,
+    pub version: Version,
+}
+
+/// The full constraint set for a package, e.g. `>=2.28,<3`.
+#[derive(Debug, Clone, Default)]
+pub struct VersionSet {
+    pub reqs: Vec<VersionReq>,
+}
+
+impl VersionSet {
+    pub fn parse(spec: &str) -> VersionSet {
+        let mut reqs = Vec::new();
+        for clause in spec.split(',') {
+            let clause = clause.trim();
+            if clause.is_empty() {
+                continue;
+            }
+            for op in &[">=", "<=", "==", "!=", "~=", ">", "<"] {
+                if let Some(rest) = clause.strip_prefix(op) {
+                    if let Some(version) = Version::parse(rest) {
+                        reqs.push(VersionReq {
+                            op: op.to_string(),
+                            version,
+                        });
+                    }
+                    break;
+                }
+            }
+        }
+        VersionSet { reqs }
+    }
+
+    pub fn satisfies(&self, candidate: &Version) -> bool {
+        self.reqs.iter().all(|req| match req.op.as_str() {
+            ">=" => candidate >= &req.version,
+            "<=" => candidate <= &req.version,
+            "==" => candidate == &req.version,
+            "!=" => candidate != &req.version,
+            ">" => candidate > &req.version,
+            "<" => candidate < &req.version,
+            "~=" => {
+                // Compatible release: candidate >= req, and matches req's release prefix
+                // up to (but not including) the last component.
+                let mut prefix = req.version.release.clone();
+                prefix.pop();
+                candidate >= &req.version && candidate.release.starts_with(&prefix)
+            }
+            _ => true,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn alpha_alias_orders_with_short_form() {
+        // "alpha2" must not be misread through the short "a" alias, which would drop its
+        // release number to 0 and incorrectly sort it below "a1".
+        assert!(Version::parse("1.0a1").unwrap() < Version::parse("1.0alpha2").unwrap());
+        assert!(Version::parse("1.0beta1").unwrap() < Version::parse("1.0b2").unwrap());
+    }
+
+    #[test]
+    fn prerelease_sorts_before_final() {
+        assert!(Version::parse("1.0rc1").unwrap() < Version::parse("1.0").unwrap());
+        assert!(Version::parse("1.0c1").unwrap() < Version::parse("1.0").unwrap());
+    }
+
+    #[test]
+    fn local_version_sorts_after_plain() {
+        assert!(Version::parse("1.0").unwrap() < Version::parse("1.0+cpu").unwrap());
+    }
+
+    #[test]
+    fn version_set_satisfies_range() {
+        let set = VersionSet::parse(">=2.28,<3");
+        assert!(set.satisfies(&Version::parse("2.28.0").unwrap()));
+        assert!(!set.satisfies(&Version::parse("3.0").unwrap()));
+        assert!(!set.satisfies(&Version::parse("2.27").unwrap()));
+    }
+}
@@ -0,0 +1,336 @@
+use anyhow::{Context, Result};
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine as _};
+use sha2::{Digest, Sha256};
+use std::fs;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use tracing::{debug, info};
+use zip::ZipArchive;
+
+use crate::utils;
+
+// This module supersedes the wheel unpacker originally added to unblock `pip`-free installs:
+// the version here adds venv-relative targets, `.data/{scripts,data,purelib,platlib}` routing,
+// and console-script generation, so it replaced the earlier single-interpreter implementation
+// outright rather than being merged alongside it.
+
+/// The interpreter + directory layout a wheel gets unpacked into.
+struct InstallTargets {
+    python: PathBuf,
+    purelib: PathBuf,
+    platlib: PathBuf,
+    scripts: PathBuf,
+    data: PathBuf,
+}
+
+fn resolve_targets(venv_path: Option<&str>) -> Result<InstallTargets> {
+    let python = match venv_path {
+        Some(v) if cfg!(target_os = "windows") => PathBuf::from(format!("{}\\Scripts\\python.exe", v)),
+        Some(v) => PathBuf::from(format!("{}/bin/python3", v)),
+        None if cfg!(target_os = "windows") => PathBuf::from("python.exe"),
+        None => PathBuf::from("python3"),
+    };
+
+    if !utils::command_exists(python.to_str().unwrap_or("")) {
+        anyhow::bail!("Python executable not found: {}", python.display());
+    }
+
+    let output = Command::new(&python)
+        .args(&[
+            "-c",
+            "import sysconfig; print(sysconfig.get_path('purelib')); print(sysconfig.get_path('platlib')); print(sysconfig.get_path('data'))",
+        ])
+        .output()
+        .with_context(|| format!("failed to query {} for install paths", python.display()))?;
+
+    if !output.status.success() {
+        anyhow::bail!("failed to resolve install paths via {}", python.display());
+    }
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let mut lines = stdout.lines();
+    let purelib = PathBuf::from(lines.next().context("missing purelib path")?.trim());
+    let platlib = PathBuf::from(lines.next().context("missing platlib path")?.trim());
+    let data_root = PathBuf::from(lines.next().context("missing data path")?.trim());
+
+    let scripts = if let Some(v) = venv_path {
+        if cfg!(target_os = "windows") {
+            PathBuf::from(v).join("Scripts")
+        } else {
+            PathBuf::from(v).join("bin")
+        }
+    } else if cfg!(target_os = "windows") {
+        PathBuf::from("Scripts")
+    } else {
+        PathBuf::from("bin")
+    };
+
+    Ok(InstallTargets {
+        python,
+        purelib,
+        platlib,
+        scripts,
+        data: data_root,
+    })
+}
+
+/// One row of a wheel's RECORD: path, `sha256=<urlsafe-b64>` digest, byte length.
+struct RecordEntry {
+    path: String,
+    hash: String,
+    size: u64,
+}
+
+fn hash_bytes(data: &[u8]) -> String {
+    format!("sha256={}", URL_SAFE_NO_PAD.encode(Sha256::digest(data)))
+}
+
+/// Extract `{name}-{version}` from `{name}-{version}-{pytag}-{abitag}-{plat}.whl`.
+fn dist_info_prefix(wheel_path: &str) -> Result<String> {
+    let filename = Path::new(wheel_path)
+        .file_name()
+        .and_then(|f| f.to_str())
+        .context("wheel path has no filename")?;
+    let stem = filename.strip_suffix(".whl").unwrap_or(filename);
+    let parts: Vec<&str> = stem.splitn(3, '-').collect();
+    if parts.len() < 2 {
+        anyhow::bail!("malformed wheel filename: {}", filename);
+    }
+    Ok(format!("{}-{}", parts[0], parts[1]))
+}
+
+/// Unpack a wheel directly into the venv (or system interpreter), no `pip` subprocess:
+/// root-level files go to purelib, `{dist}.data/{scripts,data,purelib,platlib}/` entries are
+/// routed to their matching directory, console-script entry points are generated, and RECORD
+/// is recomputed with each installed file's path/hash/size.
+pub fn install_wheel(path: &str, venv_path: Option<&str>) -> Result<()> {
+    info!("Installing wheel: {}", path);
+
+    let dist_info = dist_info_prefix(path)?;
+    let data_dir_prefix = format!("{}.data/", dist_info);
+    let record_name = format!("{}.dist-info/RECORD", dist_info);
+    let entry_points_name = format!("{}.dist-info/entry_points.txt", dist_info);
+
+    let targets = resolve_targets(venv_path)?;
+    fs::create_dir_all(&targets.purelib)?;
+    fs::create_dir_all(&targets.scripts)?;
+
+    let file = fs::File::open(path).with_context(|| format!("failed to open wheel {}", path))?;
+    let mut archive = ZipArchive::new(file).with_context(|| format!("{} is not a valid wheel", path))?;
+
+    let mut entry_points_content = None;
+    let mut written: Vec<RecordEntry> = Vec::new();
+
+    for i in 0..archive.len() {
+        let mut entry = archive.by_index(i)?;
+        let entry_name = entry.name().to_string();
+        if entry_name == record_name {
+            continue;
+        }
+
+        let mut data = Vec::new();
+        entry.read_to_end(&mut data)?;
+
+        if entry_name == entry_points_name {
+            entry_points_content = Some(String::from_utf8_lossy(&data).to_string());
+        }
+
+        let (dest, is_script) = route_entry(&entry_name, &data_dir_prefix, &targets);
+        if let Some(parent) = dest.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        if is_script {
+            let rewritten = rewrite_shebang(&data, &targets.python);
+            fs::write(&dest, &rewritten)?;
+            set_executable(&dest)?;
+            written.push(RecordEntry {
+                path: entry_name,
+                hash: hash_bytes(&rewritten),
+                size: rewritten.len() as u64,
+            });
+        } else {
+            fs::write(&dest, &data)?;
+            written.push(RecordEntry {
+                path: entry_name,
+                hash: hash_bytes(&data),
+                size: data.len() as u64,
+            });
+        }
+    }
+
+    if let Some(content) = entry_points_content {
+        for script in parse_console_scripts(&content) {
+            install_console_script(&targets, &script)?;
+        }
+    }
+
+    fs::write(targets.purelib.join(format!("{}.dist-info/INSTALLER", dist_info)), "mint\n")?;
+    write_record(&targets.purelib, &dist_info, &written)?;
+
+    info!("✅ Successfully installed {}", path);
+    Ok(())
+}
+
+/// Route one zip entry to purelib/platlib/scripts/data per the wheel spec's `.data/` convention.
+fn route_entry(entry_name: &str, data_dir_prefix: &str, targets: &InstallTargets) -> (PathBuf, bool) {
+    if let Some(rest) = entry_name.strip_prefix(data_dir_prefix) {
+        if let Some(script_rel) = rest.strip_prefix("scripts/") {
+            return (targets.scripts.join(script_rel), true);
+        }
+        if let Some(data_rel) = rest.strip_prefix("data/") {
+            return (targets.data.join(data_rel), false);
+        }
+        if let Some(rel) = rest.strip_prefix("purelib/") {
+            return (targets.purelib.join(rel), false);
+        }
+        if let Some(rel) = rest.strip_prefix("platlib/") {
+            return (targets.platlib.join(rel), false);
+        }
+        return (targets.purelib.join(rest), false);
+    }
+    (targets.purelib.join(entry_name), false)
+}
+
+fn rewrite_shebang(data: &[u8], python: &Path) -> Vec<u8> {
+    if !data.starts_with(b"#!python") {
+        return data.to_vec();
+    }
+    let first_newline = data.iter().position(|&b| b == b'\n').unwrap_or(data.len());
+    let mut out = format!("#!{}", python.display()).into_bytes();
+    out.extend_from_slice(&data[first_newline..]);
+    out
+}
+
+struct ConsoleScript {
+    name: String,
+    module: String,
+    func: String,
+}
+
+fn parse_console_scripts(entry_points: &str) -> Vec<ConsoleScript> {
+    let mut scripts = Vec::new();
+    let mut in_section = false;
+
+    for line in entry_points.lines() {
+        let line = line.trim();
+        if line.starts_with('[') {
+            in_section = line == "[console_scripts]";
+            continue;
+        }
+        if !in_section || line.is_empty() {
+            continue;
+        }
+        if let Some((name, target)) = line.split_once('=') {
+            let target = target.trim();
+            if let Some((module, func)) = target.split_once(':') {
+                scripts.push(ConsoleScript {
+                    name: name.trim().to_string(),
+                    module: module.trim().to_string(),
+                    func: func.trim().to_string(),
+                });
+            }
+        }
+    }
+
+    scripts
+}
+
+#[cfg(unix)]
+fn install_console_script(targets: &InstallTargets, script: &ConsoleScript) -> Result<()> {
+    let script_path = targets.scripts.join(&script.name);
+    let body = format!(
+        "#!{}\nimport sys\nfrom {} import {}\nif __name__ == '__main__':\n    sys.exit({}())\n",
+        targets.python.display(),
+        script.module,
+        script.func.split('.').next().unwrap_or(&script.func),
+        script.func,
+    );
+    fs::write(&script_path, body)?;
+    set_executable(&script_path)?;
+    Ok(())
+}
+
+#[cfg(windows)]
+fn install_console_script(targets: &InstallTargets, script: &ConsoleScript) -> Result<()> {
+    // No native .exe trampoline builder in this environment; fall back to a `-script.py`
+    // launcher the same way pip's older Windows installs did.
+    let script_path = targets.scripts.join(format!("{}-script.py", script.name));
+    let body = format!(
+        "import sys\nfrom {} import {}\nif __name__ == '__main__':\n    sys.exit({}())\n",
+        script.module,
+        script.func.split('.').next().unwrap_or(&script.func),
+        script.func,
+    );
+    fs::write(&script_path, body)?;
+    Ok(())
+}
+
+#[cfg(unix)]
+fn set_executable(path: &Path) -> Result<()> {
+    use std::os::unix::fs::PermissionsExt;
+    let mut perms = fs::metadata(path)?.permissions();
+    perms.set_mode(0o755);
+    fs::set_permissions(path, perms)?;
+    Ok(())
+}
+
+#[cfg(not(unix))]
+fn set_executable(_path: &Path) -> Result<()> {
+    Ok(())
+}
+
+fn write_record(purelib: &Path, dist_info: &str, entries: &[RecordEntry]) -> Result<()> {
+    let record_path = purelib.join(format!("{}.dist-info/RECORD", dist_info));
+    let mut out = String::new();
+    for entry in entries {
+        out.push_str(&format!("{},{},{}\n", entry.path, entry.hash, entry.size));
+    }
+    out.push_str(&format!("{}.dist-info/RECORD,,\n", dist_info));
+
+    if let Some(parent) = record_path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(&record_path, out)?;
+    debug!("Wrote RECORD for {} ({} entries)", dist_info, entries.len());
+    Ok(())
+}
+
+/// The directory wheels get unpacked into (`site-packages`), for anything that needs to scan
+/// installed packages without going through the rest of `InstallTargets`.
+pub fn site_packages_dir(venv_path: Option<&str>) -> Result<PathBuf> {
+    Ok(resolve_targets(venv_path)?.purelib)
+}
+
+/// Read an installed dist-info's RECORD, for precise uninstall.
+pub fn installed_files(pkg_name: &str, venv_path: Option<&str>) -> Result<Vec<PathBuf>> {
+    let targets = resolve_targets(venv_path)?;
+    let dist_info_dir = find_dist_info(&targets.purelib, pkg_name)?;
+    let record_path = dist_info_dir.join("RECORD");
+    let content = fs::read_to_string(&record_path)
+        .with_context(|| format!("no RECORD found for {}", pkg_name))?;
+
+    let mut files = Vec::new();
+    for line in content.lines() {
+        if let Some(rel_path) = line.split(',').next() {
+            if !rel_path.is_empty() {
+                files.push(targets.purelib.join(rel_path));
+            }
+        }
+    }
+    Ok(files)
+}
+
+fn find_dist_info(purelib: &Path, pkg_name: &str) -> Result<PathBuf> {
+    let needle = pkg_name.to_lowercase().replace('-', "_");
+    for entry in fs::read_dir(purelib)? {
+        let entry = entry?;
+        let name = entry.file_name();
+        let name = name.to_string_lossy();
+        if name.ends_with(".dist-info") && name.to_lowercase().replace('-', "_").starts_with(&needle) {
+            return Ok(entry.path());
+        }
+    }
+    anyhow::bail!("no installed dist-info found for {}", pkg_name)
+}